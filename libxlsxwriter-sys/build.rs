@@ -102,6 +102,11 @@ fn main() -> io::Result<()> {
             .include("include");
     }
 
+    // Keep the XML files of the workbook in memory with `fmemopen` instead of temporary files.
+    if env::var("CARGO_FEATURE_USE_FMEMOPEN").is_ok() {
+        build.define("USE_FMEMOPEN", None);
+    }
+
     // Make `libxlsxwriter` use DTOA for number formating to avoid locale-specific C functions.
     build.define("USE_DTOA_LIBRARY", None);
 
@@ -0,0 +1,155 @@
+use super::{ChartAxis, ChartLine};
+use crate::{convert_bool, XlsxError};
+
+impl<'a> ChartAxis<'a> {
+    /// Turn on/off the major gridlines for a chart axis.
+    ///
+    /// Default state for the y-axis of most chart types is to have major gridlines turned on.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_axis-set_major_gridlines-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i*10 + 2).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Line);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// chart.x_axis().set_major_gridlines(true);
+    /// chart.y_axis().set_minor_gridlines(true);
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn set_major_gridlines(&mut self, visible: bool) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_major_gridlines_set_visible(
+                self.axis,
+                convert_bool(visible),
+            );
+        }
+    }
+
+    /// Turn on/off the minor gridlines for a chart axis. Minor gridlines are off by default.
+    pub fn set_minor_gridlines(&mut self, visible: bool) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_minor_gridlines_set_visible(
+                self.axis,
+                convert_bool(visible),
+            );
+        }
+    }
+
+    /// Set the line properties of the major gridlines. Setting a line also makes the gridlines visible.
+    pub fn set_major_gridlines_line(&mut self, line: &ChartLine) {
+        let mut line = line.value();
+        unsafe {
+            libxlsxwriter_sys::chart_axis_major_gridlines_set_line(self.axis, &mut line);
+        }
+    }
+
+    /// Set the line properties of the minor gridlines. Setting a line also makes the gridlines visible.
+    pub fn set_minor_gridlines_line(&mut self, line: &ChartLine) {
+        let mut line = line.value();
+        unsafe {
+            libxlsxwriter_sys::chart_axis_minor_gridlines_set_line(self.axis, &mut line);
+        }
+    }
+
+    /// Set the increment of the major units in the axis range.
+    ///
+    /// This is only valid for value axes and date axes. For example, a major unit of `30.0` on an axis of date
+    /// serial numbers gives roughly monthly tick marks.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_axis-set_major_unit-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i*10 + 2).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// chart.y_axis().set_major_unit(20.);
+    /// chart.y_axis().set_minor_unit(5.);
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn set_major_unit(&mut self, unit: f64) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_major_unit(self.axis, unit);
+        }
+    }
+
+    /// Set the increment of the minor units in the axis range. See `ChartAxis.set_major_unit()`.
+    pub fn set_minor_unit(&mut self, unit: f64) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_minor_unit(self.axis, unit);
+        }
+    }
+
+    /// Set the interval between the category labels of an axis. For example, an interval of `3` shows every
+    /// third label. The default interval is `1`.
+    ///
+    /// This is only valid for category axes.
+    pub fn set_interval_unit(&mut self, unit: u16) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_interval_unit(self.axis, unit);
+        }
+    }
+
+    /// Set the interval between the tick marks of a category axis. The default interval is `1`.
+    ///
+    /// This is only valid for category axes.
+    pub fn set_interval_tick(&mut self, unit: u16) {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_interval_tick(self.axis, unit);
+        }
+    }
+
+    /// Set the number format of the axis labels, using the same syntax as `Format.set_num_format()`.
+    ///
+    /// For a time series, write the dates as date cells and use them as the categories of the series.
+    /// The axis labels are then shown with this format, and `ChartAxis.set_interval_unit()` controls how many
+    /// of them are shown. libxlsxwriter writes the axis as a category axis, so every date is a separate category
+    /// and gaps between the dates are not scaled.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_axis-set_num_format-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let mut date_format = Format::new();
+    /// date_format.set_num_format("yyyy-mm-dd");
+    /// for i in 0..12 {
+    ///     let date = DateTime::date(2021, i as i8 + 1, 1);
+    ///     worksheet.write_datetime(i, 0, &date, Some(&date_format))?;
+    ///     worksheet.write_number(i, 1, (i * 10).into(), None)?;
+    /// }
+    /// let mut chart = workbook.add_chart(ChartType::Line);
+    /// chart.add_series(Some("=Sheet1!$A$1:$A$12"), Some("=Sheet1!$B$1:$B$12"))?;
+    /// let mut x_axis = chart.x_axis();
+    /// x_axis.set_num_format("mmm-yy")?;
+    /// x_axis.set_interval_unit(2);
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn set_num_format(&mut self, num_format: &str) -> Result<(), XlsxError> {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_num_format(
+                self.axis,
+                self._workbook.register_str(num_format)?,
+            );
+        }
+        Ok(())
+    }
+
+    /// Set the name (title) of the axis. The name can also be a formula such as `=Sheet1!$A$1`.
+    pub fn set_name(&mut self, name: &str) -> Result<(), XlsxError> {
+        unsafe {
+            libxlsxwriter_sys::chart_axis_set_name(self.axis, self._workbook.register_str(name)?);
+        }
+        Ok(())
+    }
+}
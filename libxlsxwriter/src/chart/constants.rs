@@ -1,4 +1,4 @@
-use super::{ChartDashType, ChartMarkerType, ChartPatternType, ChartType};
+use super::{ChartDashType, ChartMarkerType, ChartPatternType, ChartTrendlineType, ChartType};
 
 impl ChartType {
     pub(crate) fn value(self) -> u8 {
@@ -265,3 +265,29 @@ impl ChartMarkerType {
         value as u8
     }
 }
+
+impl ChartTrendlineType {
+    pub(crate) fn value(self) -> u8 {
+        let value = match self {
+            ChartTrendlineType::Exponential => {
+                libxlsxwriter_sys::lxw_chart_trendline_type_LXW_CHART_TRENDLINE_TYPE_EXP
+            }
+            ChartTrendlineType::Linear => {
+                libxlsxwriter_sys::lxw_chart_trendline_type_LXW_CHART_TRENDLINE_TYPE_LINEAR
+            }
+            ChartTrendlineType::Logarithmic => {
+                libxlsxwriter_sys::lxw_chart_trendline_type_LXW_CHART_TRENDLINE_TYPE_LOG
+            }
+            ChartTrendlineType::MovingAverage => {
+                libxlsxwriter_sys::lxw_chart_trendline_type_LXW_CHART_TRENDLINE_TYPE_AVERAGE
+            }
+            ChartTrendlineType::Polynomial => {
+                libxlsxwriter_sys::lxw_chart_trendline_type_LXW_CHART_TRENDLINE_TYPE_POLY
+            }
+            ChartTrendlineType::Power => {
+                libxlsxwriter_sys::lxw_chart_trendline_type_LXW_CHART_TRENDLINE_TYPE_POWER
+            }
+        };
+        value as u8
+    }
+}
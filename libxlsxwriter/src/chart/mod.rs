@@ -1,10 +1,13 @@
+mod axis;
 mod constants;
 mod series;
 mod structs;
 
 use crate::format::FormatColor;
-use crate::XlsxError;
+use crate::worksheet::ROW_COUNT;
+use crate::{WorksheetRow, XlsxError};
 
+pub use self::axis::*;
 pub use self::constants::*;
 pub use self::series::*;
 pub use self::structs::*;
@@ -45,9 +48,34 @@ use super::Workbook;
 /// Add one or more data series to the chart which refers to data in the workbook using `Chart.add_series`.
 /// Configure the chart with the other available functions shown below.
 /// Insert the chart into a worksheet using `Worksheet.insert_chart`.
+///
+/// ### Lifetime
+/// The chart is owned by the workbook in libxlsxwriter, and a `Chart` borrows the `Workbook` that created it.
+/// Dropping a `Chart` does not free the chart, so it can be dropped at any time after it was inserted.
+/// A `Chart` cannot be used after the workbook has been closed:
+/// ```compile_fail
+/// # use xlsxwriter::prelude::*;
+/// # fn main() -> Result<(), XlsxError> {
+/// let workbook = Workbook::new("test-chart-lifetime-1.xlsx")?;
+/// let mut chart = workbook.add_chart(ChartType::Column);
+/// workbook.close()?;
+/// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?; // error: `workbook` was moved by `close`
+/// # Ok(())
+/// # }
+/// ```
+/// and it cannot outlive the workbook:
+/// ```compile_fail
+/// # use xlsxwriter::prelude::*;
+/// fn new_chart<'a>() -> Chart<'a> {
+///     let workbook = Workbook::new("test-chart-lifetime-2.xlsx").unwrap();
+///     workbook.add_chart(ChartType::Column) // error: returns a value referencing a local variable
+/// }
+/// ```
 pub struct Chart<'a> {
     pub(crate) _workbook: &'a Workbook,
     pub(crate) chart: *mut libxlsxwriter_sys::lxw_chart,
+    /// Size in pixels set with [`Chart::set_size_pixels`].
+    pub(crate) size: Option<(u32, u32)>,
 }
 
 impl<'a> Chart<'a> {
@@ -184,6 +212,43 @@ impl<'a> Chart<'a> {
         })
     }
 
+    /// Add a series with values that are not written to a worksheet.
+    ///
+    /// Excel charts can only show data from cells, so the values are written to a hidden worksheet named `_ChartData`,
+    /// which is added to the workbook the first time this function is called. Each series uses a new column of it.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart-add_series_from_slice-1.xlsx")?;
+    /// let mut worksheet = workbook.add_worksheet(None)?;
+    /// let mut chart = workbook.add_chart(ChartType::Line);
+    /// chart.add_series_from_slice(&[1.0, 4.0, 9.0, 16.0])?;
+    /// worksheet.insert_chart(1, 1, &chart)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// Add the visible worksheets before calling this function. A hidden worksheet cannot be the first or active sheet.
+    pub fn add_series_from_slice(&mut self, values: &[f64]) -> Result<ChartSeries<'a>, XlsxError> {
+        if values.is_empty() || values.len() > ROW_COUNT as usize {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ));
+        }
+        let (mut worksheet, col) = self._workbook.chart_data_column()?;
+        for (row, value) in (0..).zip(values.iter()) {
+            worksheet.write_number(row, col, *value, None)?;
+        }
+        let mut series = self.add_series(None, None)?;
+        series.set_values(
+            CHART_DATA_SHEET_NAME,
+            0,
+            col,
+            (values.len() - 1) as WorksheetRow,
+            col,
+        )?;
+        Ok(series)
+    }
+
     /// The `chart_title_set_name` function sets the name (title) for the chart. The name is displayed above the chart.
     /// The name parameter can also be a formula such as `=Sheet1!$A$1` to point to a cell in the workbook that contains the name.
     /// The Excel default is to have no chart title.
@@ -196,6 +261,99 @@ impl<'a> Chart<'a> {
         }
         Ok(())
     }
+
+    /// Add drop lines to a line or area chart. Drop lines are vertical lines from each data point down to the
+    /// category axis. Pass `None` to use the default line style.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart-set_drop_lines-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i*10 + 2).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Line);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// let mut drop_line = ChartLine::new();
+    /// drop_line.dash_type = ChartDashType::Dash;
+    /// chart.set_drop_lines(Some(&drop_line));
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn set_drop_lines(&mut self, line: Option<&ChartLine>) {
+        let mut line = line.map(ChartLine::value);
+        unsafe {
+            libxlsxwriter_sys::chart_set_drop_lines(
+                self.chart,
+                line.as_mut().map_or(std::ptr::null_mut(), |x| x as *mut _),
+            );
+        }
+    }
+
+    /// Add high-low lines to a line chart. High-low lines are vertical lines from the highest to the lowest value of
+    /// all series at each category. Pass `None` to use the default line style.
+    pub fn set_high_low_lines(&mut self, line: Option<&ChartLine>) {
+        let mut line = line.map(ChartLine::value);
+        unsafe {
+            libxlsxwriter_sys::chart_set_high_low_lines(
+                self.chart,
+                line.as_mut().map_or(std::ptr::null_mut(), |x| x as *mut _),
+            );
+        }
+    }
+
+    /// Set the size of the chart in pixels when it is inserted with [`crate::Worksheet::insert_chart`], instead of
+    /// the default size of 480x288 pixels. The size does not depend on the cells the chart is inserted on.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart-set_size_pixels-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i*10).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Line);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// chart.set_size_pixels(640, 400);
+    /// worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// libxlsxwriter scales the default size to the given size, and converts it to the EMUs (English Metric Units,
+    /// 9525 per pixel) of the xlsx file when the workbook is closed.
+    pub fn set_size_pixels(&mut self, width_px: u32, height_px: u32) -> &mut Self {
+        self.size = Some((width_px, height_px));
+        self
+    }
+
+    /// Get the x-axis of the chart, usually the category axis, to configure it with the `ChartAxis` functions.
+    /// For bar charts this is the vertical axis.
+    pub fn x_axis(&mut self) -> ChartAxis<'a> {
+        ChartAxis {
+            _workbook: self._workbook,
+            axis: unsafe { (*self.chart).x_axis },
+        }
+    }
+
+    /// Get the y-axis of the chart, usually the value axis, to configure it with the `ChartAxis` functions.
+    /// For bar charts this is the horizontal axis.
+    pub fn y_axis(&mut self) -> ChartAxis<'a> {
+        ChartAxis {
+            _workbook: self._workbook,
+            axis: unsafe { (*self.chart).y_axis },
+        }
+    }
+}
+
+/// Name of the hidden worksheet used by [`Chart::add_series_from_slice`].
+pub(crate) const CHART_DATA_SHEET_NAME: &str = "_ChartData";
+
+/// Struct to represent an Excel chart axis.
+/// This struct is returned by the `chart.x_axis` and `chart.y_axis` functions. It is used to set the axis gridlines, units and other properties.
+pub struct ChartAxis<'a> {
+    pub(crate) _workbook: &'a Workbook,
+    pub(crate) axis: *mut libxlsxwriter_sys::lxw_chart_axis,
 }
 
 /// Struct to represent an Excel chart data series.
@@ -380,6 +538,23 @@ pub enum ChartPatternType {
     SolidDiamond,
 }
 
+/// Type of a chart series trendline, see `ChartSeries.set_trendline()`.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ChartTrendlineType {
+    /// Exponential trendline.
+    Exponential,
+    /// Linear trendline.
+    Linear,
+    /// Logarithmic trendline.
+    Logarithmic,
+    /// Moving average trendline. The value is the period, 2 or more.
+    MovingAverage,
+    /// Polynomial trendline. The value is the order, 2 to 6.
+    Polynomial,
+    /// Power trendline.
+    Power,
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ChartMarkerType {
     MarkerAutomatic,
@@ -1,4 +1,4 @@
-use super::{ChartFill, ChartLine, ChartMarkerType, ChartPattern, ChartSeries};
+use super::{ChartFill, ChartLine, ChartMarkerType, ChartPattern, ChartSeries, ChartTrendlineType};
 use crate::{convert_bool, WorksheetCol, WorksheetRow, XlsxError};
 
 impl<'a> ChartSeries<'a> {
@@ -50,6 +50,9 @@ impl<'a> ChartSeries<'a> {
     /// The categories and values of a chart data series are generally set using the `Chart.add_series()` function and Excel range formulas like "=Sheet1!$A$2:$A$7".
     ///
     /// The `Chart.series_set_values()` function is an alternative method that is easier to generate programmatically. See the documentation for `ChartSeries.set_categories()` above.
+    ///
+    /// Both functions can also be called on a series that was created with ranges, or called again, to replace the range
+    /// while a chart is built up incrementally. The last range set before the workbook is closed is used.
     pub fn set_values(
         &mut self,
         sheet_name: &str,
@@ -364,9 +367,9 @@ impl<'a> ChartSeries<'a> {
     /// # }
     /// ```
     /// ![Result Image](https://github.com/informationsea/xlsxwriter-rs/raw/master/images/test-chart_series-set_marker_type-1.png)
-    pub fn set_marker_type(&mut self, maker_type: ChartMarkerType) {
+    pub fn set_marker_type(&mut self, marker_type: ChartMarkerType) {
         unsafe {
-            libxlsxwriter_sys::chart_series_set_marker_type(self.chart_series, maker_type.value());
+            libxlsxwriter_sys::chart_series_set_marker_type(self.chart_series, marker_type.value());
         }
     }
 
@@ -394,8 +397,8 @@ impl<'a> ChartSeries<'a> {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn set_marker_size(&mut self, maker_size: u8) {
-        unsafe { libxlsxwriter_sys::chart_series_set_marker_size(self.chart_series, maker_size) }
+    pub fn set_marker_size(&mut self, marker_size: u8) {
+        unsafe { libxlsxwriter_sys::chart_series_set_marker_size(self.chart_series, marker_size) }
     }
 
     /// Set the line/border properties of a chart marker.
@@ -434,7 +437,7 @@ impl<'a> ChartSeries<'a> {
         }
     }
 
-    /// Set the line/border properties of a chart marker.
+    /// Set the fill properties of a chart marker.
     /// ```rust
     /// # use xlsxwriter::prelude::*;
     /// # fn main() -> Result<(), XlsxError> {
@@ -533,4 +536,70 @@ impl<'a> ChartSeries<'a> {
     pub fn set_labels(&mut self) {
         unsafe { libxlsxwriter_sys::chart_series_set_labels(self.chart_series) }
     }
+
+    /// Turn on data labels and choose what they show. Data labels show only the value by default.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_series-set_labels_options-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i*10 + 2).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// let mut series = chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// series.set_labels_options(true, true, false);
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn set_labels_options(
+        &mut self,
+        show_value: bool,
+        show_category: bool,
+        show_series_name: bool,
+    ) {
+        unsafe {
+            libxlsxwriter_sys::chart_series_set_labels(self.chart_series);
+            libxlsxwriter_sys::chart_series_set_labels_options(
+                self.chart_series,
+                convert_bool(show_series_name),
+                convert_bool(show_category),
+                convert_bool(show_value),
+            );
+        }
+    }
+
+    /// Add a trendline to the series.
+    ///
+    /// `value` is the order of a [`ChartTrendlineType::Polynomial`] trendline (2 to 6) or the period of a
+    /// [`ChartTrendlineType::MovingAverage`] trendline (2 or more). It is ignored for the other types.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-chart_series-set_trendline-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i*10 + 2).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Line);
+    /// let mut series = chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// series.set_trendline(ChartTrendlineType::Polynomial, 3);
+    /// # worksheet.insert_chart(1, 3, &chart)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn set_trendline(&mut self, trendline_type: ChartTrendlineType, value: u8) {
+        let value = match trendline_type {
+            ChartTrendlineType::Polynomial | ChartTrendlineType::MovingAverage => value,
+            _ => 0,
+        };
+        unsafe {
+            libxlsxwriter_sys::chart_series_set_trendline(
+                self.chart_series,
+                trendline_type.value(),
+                value,
+            );
+        }
+    }
 }
@@ -7,10 +7,57 @@ use std::fmt::{self, Display};
 pub(crate) enum XlsxErrorSource {
     LibXlsxWriter(libxlsxwriter_sys::lxw_error),
     NumberOfColumnsIsNotMatched,
+    ChartAlreadyInserted,
+    InvalidCellReference(String),
+    InvalidRichString(String),
+    InvalidVbaName(String),
+    InvalidOutputPath(String, String),
+    InvalidInternalLink(String),
+    InvalidWorksheetName(String, WorksheetNameError),
+    InvalidFileLink(String, String),
+    InvalidZoom(u16),
+    ValidationListTooLong(usize),
+    TooManyHyperlinks(usize),
+    TooltipTooLong(usize),
+    InvalidFontSize(f64),
+    Io(std::io::ErrorKind, String),
     Unknown,
     NulError(std::ffi::NulError),
 }
 
+/// Reason why a worksheet name was rejected, see [`XlsxError::worksheet_name_error`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorksheetNameError {
+    Empty,
+    /// The name is longer than 31 characters.
+    TooLong,
+    /// The name contains one of `[ ] : * ? / \`.
+    InvalidCharacter(char),
+    /// The name starts or ends with an apostrophe.
+    Apostrophe,
+    /// Another worksheet has the same name.
+    AlreadyUsed,
+    /// "History" is reserved by Excel.
+    Reserved,
+}
+
+impl Display for WorksheetNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorksheetNameError::Empty => write!(f, "the name is empty"),
+            WorksheetNameError::TooLong => write!(f, "longer than 31 characters"),
+            WorksheetNameError::InvalidCharacter(c) => {
+                write!(f, "the character {:?} is not allowed", c)
+            }
+            WorksheetNameError::Apostrophe => {
+                write!(f, "the name cannot start or end with an apostrophe")
+            }
+            WorksheetNameError::AlreadyUsed => write!(f, "the name is already used"),
+            WorksheetNameError::Reserved => write!(f, "the name is reserved by Excel"),
+        }
+    }
+}
+
 impl Error for XlsxError {}
 
 impl XlsxError {
@@ -20,11 +67,133 @@ impl XlsxError {
         }
     }
 
+    pub(crate) fn chart_already_inserted() -> XlsxError {
+        XlsxError {
+            source: XlsxErrorSource::ChartAlreadyInserted,
+        }
+    }
+
+    pub(crate) fn invalid_cell_reference(reference: &str) -> XlsxError {
+        XlsxError {
+            source: XlsxErrorSource::InvalidCellReference(reference.to_string()),
+        }
+    }
+
+    pub(crate) fn invalid_rich_string(reason: &str) -> XlsxError {
+        XlsxError {
+            source: XlsxErrorSource::InvalidRichString(reason.to_string()),
+        }
+    }
+
+    pub(crate) fn invalid_vba_name(name: &str) -> XlsxError {
+        XlsxError {
+            source: XlsxErrorSource::InvalidVbaName(name.to_string()),
+        }
+    }
+
+    pub(crate) fn invalid_output_path(path: &str, reason: String) -> XlsxError {
+        XlsxError {
+            source: XlsxErrorSource::InvalidOutputPath(path.to_string(), reason),
+        }
+    }
+
+    pub(crate) fn invalid_internal_link(location: &str) -> XlsxError {
+        XlsxError {
+            source: XlsxErrorSource::InvalidInternalLink(location.to_string()),
+        }
+    }
+
+    pub(crate) fn invalid_file_link(path: &str, reason: &str) -> XlsxError {
+        XlsxError {
+            source: XlsxErrorSource::InvalidFileLink(path.to_string(), reason.to_string()),
+        }
+    }
+
+    pub(crate) fn invalid_worksheet_name(name: &str, reason: WorksheetNameError) -> XlsxError {
+        XlsxError {
+            source: XlsxErrorSource::InvalidWorksheetName(name.to_string(), reason),
+        }
+    }
+
+    pub(crate) fn invalid_zoom(scale: u16) -> XlsxError {
+        XlsxError {
+            source: XlsxErrorSource::InvalidZoom(scale),
+        }
+    }
+
+    pub(crate) fn validation_list_too_long(length: usize) -> XlsxError {
+        XlsxError {
+            source: XlsxErrorSource::ValidationListTooLong(length),
+        }
+    }
+
+    pub(crate) fn too_many_hyperlinks(count: usize) -> XlsxError {
+        XlsxError {
+            source: XlsxErrorSource::TooManyHyperlinks(count),
+        }
+    }
+
+    pub(crate) fn tooltip_too_long(length: usize) -> XlsxError {
+        XlsxError {
+            source: XlsxErrorSource::TooltipTooLong(length),
+        }
+    }
+
+    pub(crate) fn invalid_font_size(font_size: f64) -> XlsxError {
+        XlsxError {
+            source: XlsxErrorSource::InvalidFontSize(font_size),
+        }
+    }
+
     pub(crate) fn unknown_error() -> XlsxError {
         XlsxError {
             source: XlsxErrorSource::Unknown,
         }
     }
+
+    /// Return the rejected name and the reason if this error was caused by an invalid worksheet name.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-error-worksheet_name_error.xlsx")?;
+    /// let error = workbook.add_worksheet(Some("Q1/Q2")).unwrap_err();
+    /// assert_eq!(
+    ///     error.worksheet_name_error(),
+    ///     Some(("Q1/Q2", WorksheetNameError::InvalidCharacter('/')))
+    /// );
+    /// # workbook.close()
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn worksheet_name_error(&self) -> Option<(&str, WorksheetNameError)> {
+        match &self.source {
+            XlsxErrorSource::InvalidWorksheetName(name, reason) => Some((name, *reason)),
+            _ => None,
+        }
+    }
+
+    /// Return the `lxw_error` code if this error was returned by libxlsxwriter, to compare it with the constants of
+    /// `libxlsxwriter_sys`. Errors detected by this crate, such as an invalid worksheet name, have no code.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-error-raw_code.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let error = worksheet.write_string(1_048_576, 0, "below the last row", None).unwrap_err();
+    /// assert_eq!(
+    ///     error.raw_code(),
+    ///     Some(libxlsxwriter_sys::lxw_error_LXW_ERROR_WORKSHEET_INDEX_OUT_OF_RANGE)
+    /// );
+    /// # workbook.close()
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn raw_code(&self) -> Option<libxlsxwriter_sys::lxw_error> {
+        match &self.source {
+            XlsxErrorSource::LibXlsxWriter(error) => Some(*error),
+            _ => None,
+        }
+    }
 }
 
 impl Display for XlsxError {
@@ -39,6 +208,75 @@ impl Display for XlsxError {
                     "Number of columns in an option is not equal to table size"
                 )
             }
+            XlsxErrorSource::ChartAlreadyInserted => {
+                write!(
+                    f,
+                    "The chart has already been inserted into a worksheet; create a new chart for each insertion"
+                )
+            }
+            XlsxErrorSource::InvalidCellReference(reference) => {
+                write!(f, "Invalid cell reference: {}", reference)
+            }
+            XlsxErrorSource::InvalidRichString(reason) => {
+                write!(f, "Invalid rich string: {}", reason)
+            }
+            XlsxErrorSource::InvalidVbaName(name) => {
+                write!(
+                    f,
+                    "Invalid VBA name: {:?} must start with a letter and contain only letters, digits and underscores (at most 31 characters)",
+                    name
+                )
+            }
+            XlsxErrorSource::InvalidOutputPath(path, reason) => {
+                write!(f, "Cannot create workbook {:?}: {}", path, reason)
+            }
+            XlsxErrorSource::InvalidInternalLink(location) => {
+                write!(
+                    f,
+                    "Internal link target {:?} is not a cell, range or defined name",
+                    location
+                )
+            }
+            XlsxErrorSource::InvalidFileLink(path, reason) => {
+                write!(f, "Invalid file link {:?}: {}", path, reason)
+            }
+            XlsxErrorSource::InvalidWorksheetName(name, reason) => {
+                write!(f, "Invalid worksheet name {:?}: {}", name, reason)
+            }
+            XlsxErrorSource::InvalidZoom(scale) => {
+                write!(f, "Invalid zoom {}: must be between 10 and 400", scale)
+            }
+            XlsxErrorSource::ValidationListTooLong(length) => {
+                write!(
+                    f,
+                    "Data validation list is {} characters long, Excel allows at most 255; use DataValidation::list_from_range for longer lists",
+                    length
+                )
+            }
+            XlsxErrorSource::TooManyHyperlinks(count) => {
+                write!(
+                    f,
+                    "The worksheet already has {} hyperlinks, the maximum Excel allows",
+                    count
+                )
+            }
+            XlsxErrorSource::TooltipTooLong(length) => {
+                write!(
+                    f,
+                    "Hyperlink tooltip is {} characters long, Excel allows at most 255",
+                    length
+                )
+            }
+            XlsxErrorSource::InvalidFontSize(font_size) => {
+                write!(
+                    f,
+                    "Invalid font size {}: must be between 1 and 409 points",
+                    font_size
+                )
+            }
+            XlsxErrorSource::Io(_, message) => {
+                write!(f, "I/O error: {}", message)
+            }
             XlsxErrorSource::NulError(e) => {
                 write!(f, "Null bytes in string: {}", e)
             }
@@ -59,3 +297,11 @@ impl From<std::ffi::NulError> for XlsxError {
         }
     }
 }
+
+impl From<std::io::Error> for XlsxError {
+    fn from(e: std::io::Error) -> Self {
+        XlsxError {
+            source: XlsxErrorSource::Io(e.kind(), e.to_string()),
+        }
+    }
+}
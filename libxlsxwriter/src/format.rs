@@ -1,4 +1,11 @@
 use crate::{CStringHelper, XlsxError};
+use std::ops::Deref;
+use std::rc::Rc;
+
+/// Smallest font size supported by Excel, in points.
+const MIN_FONT_SIZE: f64 = 1.0;
+/// Largest font size supported by Excel, in points.
+const MAX_FONT_SIZE: f64 = 409.0;
 
 #[allow(clippy::unreadable_literal)]
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
@@ -43,7 +50,123 @@ impl FormatColor {
             FormatColor::Silver => 0xC0_C0_C0,
             FormatColor::White => 0xFF_FF_FF,
             FormatColor::Yellow => 0xFF_FF_00,
-            FormatColor::Custom(x) => x,
+            FormatColor::Custom(x) => {
+                // libxlsxwriter treats 0 as "color not set", so pure black has to be passed as
+                // 0x1000000 and anything above 24 bits is masked off.
+                let rgb = x & 0xFF_FF_FF;
+                if rgb == 0 {
+                    0x1000000
+                } else {
+                    rgb
+                }
+            }
+        }
+    }
+
+    /// Resolve a theme color to the RGB color used by the default Office theme.
+    ///
+    /// libxlsxwriter writes colors as RGB values only, but every workbook it creates embeds the
+    /// default Office theme, so the resolved color matches the theme color shown in Excel's color picker.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-format-color-theme.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.set_tab_color(FormatColor::theme(ThemeColor::Accent1));
+    /// # workbook.close()
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn theme(color: ThemeColor) -> FormatColor {
+        FormatColor::Custom(color.rgb())
+    }
+}
+
+/// Colors of the default Office theme embedded by libxlsxwriter.
+#[allow(clippy::unreadable_literal)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum ThemeColor {
+    Dark1,
+    Light1,
+    Dark2,
+    Light2,
+    Accent1,
+    Accent2,
+    Accent3,
+    Accent4,
+    Accent5,
+    Accent6,
+    Hyperlink,
+    FollowedHyperlink,
+}
+
+#[allow(clippy::unreadable_literal)]
+impl ThemeColor {
+    #[must_use]
+    pub fn rgb(self) -> u32 {
+        match self {
+            ThemeColor::Dark1 => 0x00_00_00,
+            ThemeColor::Light1 => 0xFF_FF_FF,
+            ThemeColor::Dark2 => 0x1F_49_7D,
+            ThemeColor::Light2 => 0xEE_EC_E1,
+            ThemeColor::Accent1 => 0x4F_81_BD,
+            ThemeColor::Accent2 => 0xC0_50_4D,
+            ThemeColor::Accent3 => 0x9B_BB_59,
+            ThemeColor::Accent4 => 0x80_64_A2,
+            ThemeColor::Accent5 => 0x4B_AC_C6,
+            ThemeColor::Accent6 => 0xF7_96_46,
+            ThemeColor::Hyperlink => 0x00_00_FF,
+            ThemeColor::FollowedHyperlink => 0x80_00_80,
+        }
+    }
+}
+
+#[cfg(feature = "rgb")]
+impl From<rgb::RGB8> for FormatColor {
+    fn from(color: rgb::RGB8) -> FormatColor {
+        FormatColor::Custom(
+            (u32::from(color.r) << 16) | (u32::from(color.g) << 8) | u32::from(color.b),
+        )
+    }
+}
+
+/// The alpha channel is ignored because Excel colors are opaque.
+#[cfg(feature = "rgb")]
+impl From<rgb::RGBA8> for FormatColor {
+    fn from(color: rgb::RGBA8) -> FormatColor {
+        FormatColor::from(color.rgb())
+    }
+}
+
+impl From<ThemeColor> for FormatColor {
+    fn from(color: ThemeColor) -> FormatColor {
+        FormatColor::theme(color)
+    }
+}
+
+/// Currencies with a predefined number format, see [`Format::currency`].
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Currency {
+    /// US dollar, displayed as `$1,234.56`.
+    Usd,
+    /// Euro, displayed as `1.234,56 €` or `1,234.56 €` depending on the locale of Excel.
+    Eur,
+    /// Pound sterling, displayed as `£1,234.56`.
+    Gbp,
+    /// Japanese yen without decimal places, displayed as `¥1,235`.
+    Jpy,
+}
+
+impl Currency {
+    /// Number format string of the currency. The locale tag in the format makes Excel show the right symbol
+    /// regardless of the locale of the reader.
+    #[must_use]
+    pub fn num_format(self) -> &'static str {
+        match self {
+            Currency::Usd => "[$$-409]#,##0.00",
+            Currency::Eur => "#,##0.00 [$€-407]",
+            Currency::Gbp => "[$£-809]#,##0.00",
+            Currency::Jpy => "[$¥-411]#,##0",
         }
     }
 }
@@ -294,6 +417,10 @@ impl FormatBorder {
     }
 }
 
+/// Cell format, created with [`Format::new`] and the `set_*` functions.
+///
+/// Formats are compared and hashed by their settings. The workbook adds one style for each distinct format, so equal
+/// formats created separately share a style; see [`crate::Workbook::intern_format`] to share a format explicitly.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Hash, Default)]
 pub struct Format {
     font_name: Option<String>,
@@ -308,6 +435,7 @@ pub struct Format {
     num_format: Option<String>,
     unlocked: bool,
     hidden: bool,
+    quote_prefix: bool,
     align: Option<FormatAlignment>,
     vertical_align: Option<FormatVerticalAlignment>,
     rotation: Option<i16>,
@@ -329,17 +457,136 @@ pub struct Format {
     right_color: Option<FormatColor>,
 }
 
+/// A format interned with [`crate::Workbook::intern_format`]. Cloning it is cheap, and it can be used wherever a
+/// `&Format` is expected.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct FormatRef {
+    pub(crate) format: Rc<Format>,
+}
+
+impl Deref for FormatRef {
+    type Target = Format;
+
+    fn deref(&self) -> &Format {
+        &self.format
+    }
+}
+
+impl AsRef<Format> for FormatRef {
+    fn as_ref(&self) -> &Format {
+        &self.format
+    }
+}
+
 impl Format {
     #[must_use]
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Format preset displaying numbers in scientific notation with two decimal places, e.g. `1.23E+10`.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-format-scientific.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_number(0, 0, 1.23e10, Some(&Format::scientific()))?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn scientific() -> Self {
+        let mut format = Self::new();
+        format.set_num_format("0.00E+00");
+        format
+    }
+
+    /// Format preset displaying numbers with a thousands separator and the given number of decimal places,
+    /// e.g. `#,##0` for `0` decimals or `#,##0.00` for `2` decimals.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-format-number_with_thousands.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_number(0, 0, 1234567.891, Some(&Format::number_with_thousands(2)))?; // 1,234,567.89
+    /// # workbook.close()
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn number_with_thousands(decimals: u8) -> Self {
+        let mut num_format = String::from("#,##0");
+        if decimals > 0 {
+            num_format.push('.');
+            num_format.push_str(&"0".repeat(decimals.into()));
+        }
+        let mut format = Self::new();
+        format.set_num_format(&num_format);
+        format
+    }
+
+    /// Format preset displaying numbers with the given number of decimal places followed by a space and a unit,
+    /// e.g. `0.0" kg"` for `1` decimal and the unit `kg`. The unit is quoted, so it is displayed as it is.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-format-number_with_unit.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_number(0, 0, 3.5, Some(&Format::number_with_unit(1, "kg")))?; // 3.5 kg
+    /// worksheet.write_number(1, 0, 12.0, Some(&Format::number_with_unit(0, "in\"")))?; // 12 in"
+    /// # workbook.close()
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn number_with_unit(decimals: u8, unit: &str) -> Self {
+        let mut num_format = String::from("0");
+        if decimals > 0 {
+            num_format.push('.');
+            num_format.push_str(&"0".repeat(decimals.into()));
+        }
+        // A double quote can't be escaped inside a quoted literal, so it is written as an escaped character between
+        // the quoted parts.
+        let quoted: Vec<String> = format!(" {}", unit)
+            .split('"')
+            .map(|part| {
+                if part.is_empty() {
+                    String::new()
+                } else {
+                    format!("\"{}\"", part)
+                }
+            })
+            .collect();
+        num_format.push_str(&quoted.join("\\\""));
+        let mut format = Self::new();
+        format.set_num_format(&num_format);
+        format
+    }
+
+    /// Format preset for finance reports, displaying negative numbers in red and in parentheses with two decimal
+    /// places, e.g. `1,234.50` and `(1,234.50)`. Positive numbers are padded on the right so that they line up with
+    /// the closing parenthesis of negative numbers.
+    #[must_use]
+    pub fn accounting_red_negatives() -> Self {
+        let mut format = Self::new();
+        format.set_num_format("#,##0.00_);[Red](#,##0.00)");
+        format
+    }
+
+    /// Format preset displaying numbers as an amount of the given currency. See also [`crate::Worksheet::write_currency`].
+    #[must_use]
+    pub fn currency(currency: Currency) -> Self {
+        let mut format = Self::new();
+        format.set_num_format(currency.num_format());
+        format
+    }
+
     pub fn set_font_name(&mut self, font_name: &str) -> &mut Self {
         self.font_name = Some(font_name.to_string());
         self
     }
 
+    /// Set the font size in points. Fractional sizes such as `10.5` are allowed, rounded to hundredths of a point.
+    ///
+    /// Excel supports sizes from 1 to 409 points; a format with another size returns an error when it is used.
     pub fn set_font_size(&mut self, font_size: f64) -> &mut Self {
         self.font_size = Some((font_size * 100.).round() as u32);
         self
@@ -390,6 +637,25 @@ impl Format {
         self
     }
 
+    /// Turn on Excel's quote prefix for the cell, the same as typing a leading apostrophe. The value is shown and
+    /// edited as text, so a string such as `00123` keeps its leading zeros when the user edits it, and the apostrophe
+    /// is not part of the stored value.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-format-set_quote_prefix.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let mut quoted = Format::new();
+    /// quoted.set_quote_prefix();
+    /// worksheet.write_string(0, 0, "00123", Some(&quoted))?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn set_quote_prefix(&mut self) -> &mut Self {
+        self.quote_prefix = true;
+        self
+    }
+
     pub fn set_align(&mut self, align: FormatAlignment) -> &mut Self {
         self.align = Some(align);
         self
@@ -420,16 +686,43 @@ impl Format {
         self
     }
 
+    /// Set the fill pattern of the cell. The pattern is drawn with the foreground color over the background color,
+    /// so a pattern other than [`FormatPatterns::Solid`] needs both colors to be visible as a pattern.
+    ///
+    /// Excel fills a solid pattern with the foreground color. If only a background color is set with a solid pattern or
+    /// without a pattern, libxlsxwriter uses it as the foreground color, so `set_bg_color` alone gives the expected
+    /// solid fill.
+    ///
+    /// Formats with another pattern and only one of the colors are not rejected. Excel draws the pattern in black if
+    /// there is no foreground color, and over a white background if there is no background color.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-format_set_pattern-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// // A yellow cell
+    /// worksheet.write_string(0, 0, "Solid", Some(Format::new().set_bg_color(FormatColor::Yellow)))?;
+    /// // Red stripes on white
+    /// worksheet.write_string(1, 0, "Striped", Some(Format::new()
+    ///     .set_pattern(FormatPatterns::LightHorizontal)
+    ///     .set_fg_color(FormatColor::Red)
+    ///     .set_bg_color(FormatColor::White)))?;
+    /// # workbook.close()
+    /// # }
+    /// ```
     pub fn set_pattern(&mut self, pattern: FormatPatterns) -> &mut Self {
         self.pattern = Some(pattern);
         self
     }
 
+    /// Set the background color of the cell pattern. Without a pattern or with a solid pattern, and without a foreground
+    /// color, this gives a solid fill of this color. See [`Format::set_pattern`].
     pub fn set_bg_color(&mut self, color: FormatColor) -> &mut Self {
         self.bg_color = Some(color);
         self
     }
 
+    /// Set the foreground color of the cell pattern set with [`Format::set_pattern`]. This is the color of a solid fill.
     pub fn set_fg_color(&mut self, color: FormatColor) -> &mut Self {
         self.fg_color = Some(color);
         self
@@ -485,6 +778,101 @@ impl Format {
         self
     }
 
+    /// Check the settings that libxlsxwriter accepts but Excel does not.
+    pub(crate) fn validate(&self) -> Result<(), XlsxError> {
+        match self.font_size_points() {
+            Some(font_size) if !(MIN_FONT_SIZE..=MAX_FONT_SIZE).contains(&font_size) => {
+                Err(XlsxError::invalid_font_size(font_size))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// A format with only the font settings of this format.
+    pub(crate) fn font_part(&self) -> Format {
+        Format {
+            font_name: self.font_name.clone(),
+            font_size: self.font_size,
+            font_color: self.font_color,
+            bold: self.bold,
+            italic: self.italic,
+            underline: self.underline,
+            font_strikeout: self.font_strikeout,
+            font_script: self.font_script,
+            ..Format::default()
+        }
+    }
+
+    /// The pattern, foreground and background colors written to the file. Like libxlsxwriter does when the file is
+    /// written, a background color set alone for a solid fill is moved to the foreground, where Excel expects it.
+    fn fill(
+        &self,
+    ) -> (
+        Option<FormatPatterns>,
+        Option<FormatColor>,
+        Option<FormatColor>,
+    ) {
+        match (self.pattern, self.fg_color, self.bg_color) {
+            (None, None, Some(color)) | (Some(FormatPatterns::Solid), None, Some(color)) => {
+                (Some(FormatPatterns::Solid), Some(color), None)
+            }
+            fill => fill,
+        }
+    }
+
+    /// A format with only the fill settings of this format.
+    pub(crate) fn fill_part(&self) -> Format {
+        let (pattern, fg_color, bg_color) = self.fill();
+        Format {
+            pattern,
+            bg_color,
+            fg_color,
+            ..Format::default()
+        }
+    }
+
+    /// A format with only the border settings of this format.
+    pub(crate) fn border_part(&self) -> Format {
+        Format {
+            border: self.border,
+            bottom: self.bottom,
+            top: self.top,
+            left: self.left,
+            right: self.right,
+            border_color: self.border_color,
+            bottom_color: self.bottom_color,
+            top_color: self.top_color,
+            left_color: self.left_color,
+            right_color: self.right_color,
+            ..Format::default()
+        }
+    }
+
+    /// Font size in points, or `None` if the default font size is used.
+    pub(crate) fn font_size_points(&self) -> Option<f64> {
+        self.font_size.map(|font_size| f64::from(font_size) / 100.0)
+    }
+
+    /// Set the font name and size of `default_font` on `format`, unless this format sets its own.
+    pub(crate) fn set_internal_default_font(
+        &self,
+        format: *mut libxlsxwriter_sys::lxw_format,
+        default_font: &Format,
+    ) -> Result<(), XlsxError> {
+        let mut c_string_helper = CStringHelper::new();
+        unsafe {
+            if let (None, Some(font_name)) = (&self.font_name, default_font.font_name.as_deref()) {
+                libxlsxwriter_sys::format_set_font_name(format, c_string_helper.add(font_name)?);
+            }
+
+            if let (None, Some(font_size)) = (self.font_size, default_font.font_size) {
+                let font_size: f64 = font_size.into();
+                libxlsxwriter_sys::format_set_font_size(format, font_size / 100.0);
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn set_internal_format(
         &self,
         format: *mut libxlsxwriter_sys::lxw_format,
@@ -536,6 +924,10 @@ impl Format {
                 libxlsxwriter_sys::format_set_hidden(format);
             }
 
+            if self.quote_prefix {
+                libxlsxwriter_sys::format_set_quote_prefix(format);
+            }
+
             if let Some(align) = self.align {
                 libxlsxwriter_sys::format_set_align(format, align.value());
             }
@@ -569,7 +961,7 @@ impl Format {
             }
 
             if let Some(fg_color) = self.fg_color {
-                libxlsxwriter_sys::format_set_bg_color(format, fg_color.value());
+                libxlsxwriter_sys::format_set_fg_color(format, fg_color.value());
             }
 
             if let Some(style) = self.border {
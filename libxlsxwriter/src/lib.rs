@@ -83,6 +83,9 @@ pub mod format;
 /// xlsxwriter prelude.
 pub mod prelude;
 
+/// Cell reference helpers.
+pub mod utility;
+
 /// Manipulate Workbook.
 pub mod workbook;
 
@@ -96,6 +99,7 @@ use error::XlsxErrorSource;
 use format::*;
 use worksheet::*;
 
+pub use error::WorksheetNameError;
 pub use format::Format;
 pub use workbook::Workbook;
 pub use worksheet::Worksheet;
@@ -1,16 +1,21 @@
 pub use crate::chart::{
-    Chart, ChartDashType, ChartFill, ChartLine, ChartMarkerType, ChartPattern, ChartPatternType,
-    ChartSeries, ChartType,
+    Chart, ChartAxis, ChartDashType, ChartFill, ChartLine, ChartMarkerType, ChartPattern,
+    ChartPatternType, ChartSeries, ChartTrendlineType, ChartType,
 };
 pub use crate::format::{
-    Format, FormatAlignment, FormatBorder, FormatColor, FormatPatterns, FormatScript,
-    FormatUnderline, FormatVerticalAlignment,
+    Currency, Format, FormatAlignment, FormatBorder, FormatColor, FormatPatterns, FormatRef,
+    FormatScript, FormatUnderline, FormatVerticalAlignment, ThemeColor,
+};
+pub use crate::workbook::{
+    CloseProgress, CustomPropertyValue, DocProperties, DocumentMetadata, StyleInfo, Workbook,
+    WorkbookBuilder, WorkbookOptions,
 };
-pub use crate::workbook::Workbook;
 pub use crate::worksheet::{
-    CommentDisplayType, CommentOptions, DateTime, GridLines, HeaderFooterOptions, ImageOptions,
-    PaperType, Protection, RowColOptions, Worksheet, WorksheetCol, WorksheetRow, LXW_DEF_COL_WIDTH,
-    LXW_DEF_COL_WIDTH_PIXELS, LXW_DEF_ROW_HEIGHT, LXW_DEF_ROW_HEIGHT_PIXELS,
+    CellRange, CellValue, CommentDisplayType, CommentOptions, Cursor, DateTime, GridLines,
+    HeaderFooterOptions, IgnoreError, ImageOptions, PageSetup, PaperType, Protection, ReportStyle,
+    RowColOptions, SheetTemplate, StatsLabels, Worksheet, WorksheetCol, WorksheetRow,
+    LXW_DEF_COL_WIDTH, LXW_DEF_COL_WIDTH_PIXELS, LXW_DEF_ROW_HEIGHT, LXW_DEF_ROW_HEIGHT_PIXELS,
 };
 pub use crate::StringOrFloat;
+pub use crate::WorksheetNameError;
 pub use crate::XlsxError;
@@ -1,4 +1,10 @@
-use crate::{table::*, worksheet::DateTime};
+use crate::{
+    table::*,
+    workbook::{
+        CloseProgress, CustomPropertyValue, DocProperties, DocumentMetadata, WorkbookOptions,
+    },
+    worksheet::DateTime,
+};
 
 use super::*;
 
@@ -184,3 +190,2424 @@ fn test_add_table2() -> Result<(), XlsxError> {
     workbook.close()?;
     Ok(())
 }
+
+#[test]
+fn test_merge_range_border() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_merge_range-border.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+
+    let mut merge_format = Format::new();
+    merge_format
+        .set_border(FormatBorder::Medium)
+        .set_border_color(FormatColor::Navy)
+        .set_align(FormatAlignment::Center)
+        .set_vertical_align(FormatVerticalAlignment::VerticalCenter);
+    worksheet.merge_range(1, 1, 4, 3, "Bordered", Some(&merge_format))?;
+    // Merging a single cell is rejected by libxlsxwriter
+    assert!(worksheet
+        .merge_range(6, 1, 6, 1, "Single", Some(&merge_format))
+        .is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_merge_range_wrapped() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_merge_range_wrapped.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+
+    let format = Format::new();
+    worksheet.merge_range_wrapped(
+        0,
+        0,
+        0,
+        1,
+        "A long label that does not fit on one line of two default columns and has to be wrapped",
+        &format,
+    )?;
+    worksheet.merge_range_wrapped(2, 0, 2, 1, "Short", &format)?;
+
+    unsafe {
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, 0);
+        let cell = libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, 0);
+        assert_eq!((*(*cell).format).text_wrap, 1);
+    }
+    {
+        let state = workbook.worksheet_state(worksheet.worksheet);
+        match state.rows[&0].height {
+            RowHeight::Height(height) => assert!(height > 2.0 * LXW_DEF_ROW_HEIGHT),
+            height => panic!("unexpected row height {:?}", height),
+        }
+        assert!(!state.rows.contains_key(&2));
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_write_title() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_title.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+
+    let mut title_format = Format::new();
+    title_format.set_bold().set_font_size(16.0);
+    worksheet.write_title(0, 0, 4, "Quarterly Report", &title_format)?;
+    worksheet.write_title(2, 1, 1, "Single column", &title_format)?;
+
+    unsafe {
+        let merged = (*(*worksheet.worksheet).merged_ranges).stqh_first;
+        assert!(!merged.is_null());
+        assert_eq!(
+            (
+                (*merged).first_row,
+                (*merged).first_col,
+                (*merged).last_row,
+                (*merged).last_col
+            ),
+            (0, 0, 0, 4)
+        );
+        assert!((*merged).list_pointers.stqe_next.is_null());
+
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, 0);
+        let cell = libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, 0);
+        let format = (*cell).format;
+        assert_eq!(
+            (*format).text_h_align,
+            libxlsxwriter_sys::lxw_format_alignments_LXW_ALIGN_CENTER as u8
+        );
+        assert_eq!(
+            (*format).text_v_align,
+            libxlsxwriter_sys::lxw_format_alignments_LXW_ALIGN_VERTICAL_CENTER as u8
+        );
+        assert_eq!((*format).bold, 1);
+    }
+    // The format passed in is not modified
+    let internal = workbook.get_internal_format(&title_format)?;
+    unsafe {
+        assert_eq!((*internal).text_h_align, 0);
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_format_solid_fill_uses_fg_color() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-format_solid_fill_uses_fg_color.xlsx")?;
+    let mut bg_only = Format::new();
+    bg_only.set_bg_color(FormatColor::Red);
+    let mut solid_bg_only = Format::new();
+    solid_bg_only
+        .set_pattern(FormatPatterns::Solid)
+        .set_bg_color(FormatColor::Red);
+    let mut solid_fg = Format::new();
+    solid_fg
+        .set_pattern(FormatPatterns::Solid)
+        .set_fg_color(FormatColor::Red);
+    let internal = workbook.get_internal_format(&solid_fg)?;
+    unsafe {
+        assert_eq!((*internal).fg_color, FormatColor::Red.value());
+        assert_eq!(
+            (*internal).pattern,
+            libxlsxwriter_sys::lxw_format_patterns_LXW_PATTERN_SOLID as u8
+        );
+    }
+    // libxlsxwriter moves a background color set alone to the foreground when the file is written, so these are
+    // the same fill
+    for format in [&bg_only, &solid_bg_only].iter() {
+        let internal = workbook.get_internal_format(format)?;
+        unsafe {
+            assert_eq!((*internal).bg_color, FormatColor::Red.value());
+        }
+        assert_eq!(format.fill_part(), solid_fg.fill_part());
+    }
+
+    let mut striped = Format::new();
+    striped
+        .set_pattern(FormatPatterns::LightHorizontal)
+        .set_fg_color(FormatColor::Red)
+        .set_bg_color(FormatColor::White);
+    let internal = workbook.get_internal_format(&striped)?;
+    unsafe {
+        assert_eq!((*internal).fg_color, FormatColor::Red.value());
+        assert_eq!((*internal).bg_color, FormatColor::White.value());
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_freeze_panes_a1() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_freeze_panes_a1.xlsx")?;
+    let mut numeric = workbook.add_worksheet(None)?;
+    let mut a1 = workbook.add_worksheet(None)?;
+    numeric.freeze_panes(1, 1);
+    a1.freeze_panes_a1("B2")?;
+    unsafe {
+        let expected = (*numeric.worksheet).panes;
+        let panes = (*a1.worksheet).panes;
+        assert_eq!(
+            (panes.type_, panes.first_row, panes.first_col),
+            (expected.type_, expected.first_row, expected.first_col)
+        );
+        assert_eq!((panes.first_row, panes.first_col), (1, 1));
+    }
+    assert!(a1.freeze_panes_a1("2B").is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_setup_header_row() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_setup_header_row.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut header_format = Format::new();
+    header_format.set_bold();
+    worksheet.setup_header_row(1, &header_format, 24.0)?;
+    unsafe {
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, 1);
+        assert_eq!((*row).height, 24.0);
+        assert_eq!((*row).format, workbook.get_internal_format(&header_format)?);
+        let panes = (*worksheet.worksheet).panes;
+        assert_eq!(
+            panes.type_,
+            libxlsxwriter_sys::lxw_pane_types_FREEZE_PANES as u8
+        );
+        assert_eq!((panes.first_row, panes.first_col), (2, 0));
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_is_landscape() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_is_landscape.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    assert!(!worksheet.is_landscape());
+    worksheet.set_landscape();
+    assert!(worksheet.is_landscape());
+    assert!(workbook.get_worksheet("Sheet1")?.unwrap().is_landscape());
+    worksheet.set_portrait();
+    assert!(!worksheet.is_landscape());
+
+    let mut page_setup = PageSetup::new();
+    page_setup.landscape = true;
+    workbook.set_default_page_setup(&page_setup);
+    let worksheet = workbook.add_worksheet(None)?;
+    assert!(worksheet.is_landscape());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_error_raw_code() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-error-raw_code-2.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let error = worksheet.write_number(0, 16_384, 1.0, None).unwrap_err();
+    assert_eq!(
+        error.raw_code(),
+        Some(libxlsxwriter_sys::lxw_error_LXW_ERROR_WORKSHEET_INDEX_OUT_OF_RANGE)
+    );
+    assert_eq!(worksheet.set_zoom(9).unwrap_err().raw_code(), None);
+    assert_eq!(
+        workbook.add_worksheet(Some("")).unwrap_err().raw_code(),
+        None
+    );
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_set_zoom() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_set_zoom.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.set_zoom(10)?;
+    worksheet.set_zoom(400)?;
+    worksheet.set_zoom(150)?;
+    unsafe {
+        assert_eq!((*worksheet.worksheet).zoom, 150);
+    }
+    assert_eq!(
+        worksheet.set_zoom(9).unwrap_err().source,
+        XlsxErrorSource::InvalidZoom(9)
+    );
+    assert_eq!(
+        worksheet.set_zoom(401).unwrap_err().source,
+        XlsxErrorSource::InvalidZoom(401)
+    );
+    unsafe {
+        assert_eq!((*worksheet.worksheet).zoom, 150);
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_protect_with_editable_range() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_protect-editable_range.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+
+    let mut unlocked = Format::new();
+    unlocked.set_unlocked().set_bg_color(FormatColor::Yellow);
+    worksheet.write_string(0, 0, "Locked header", None)?;
+    for row in 1..=3 {
+        for col in 1..=2 {
+            worksheet.write_blank(row, col, Some(&unlocked))?;
+        }
+    }
+    worksheet.protect(
+        "",
+        &Protection {
+            no_select_unlocked_cells: false,
+            ..Protection::new()
+        },
+    );
+
+    let internal = workbook.get_internal_format(&unlocked)?;
+    unsafe {
+        assert_eq!((*internal).locked, 0);
+        let protection = (*worksheet.worksheet).protection;
+        assert_eq!(protection.is_configured, 1);
+        assert_eq!(protection.no_select_unlocked_cells, 0);
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_write_number_as_text() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_number_as_text.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut bold = Format::new();
+    bold.set_bold();
+    worksheet.write_number_as_text(0, 0, "007", None)?;
+    worksheet.write_number_as_text(1, 0, "00123", Some(&bold))?;
+
+    let mut text_format = Format::new();
+    text_format.set_num_format("@");
+    let mut bold_text_format = bold.clone();
+    bold_text_format.set_num_format("@");
+    unsafe {
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, 0);
+        let cell = libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, 0);
+        assert_eq!(
+            u32::from((*cell).type_),
+            libxlsxwriter_sys::cell_types_STRING_CELL
+        );
+        assert_eq!((*cell).format, workbook.get_internal_format(&text_format)?);
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, 1);
+        let cell = libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, 0);
+        assert_eq!(
+            (*cell).format,
+            workbook.get_internal_format(&bold_text_format)?
+        );
+    }
+    assert_eq!(
+        workbook
+            .worksheet_state(worksheet.worksheet)
+            .ignored_errors
+            .get(&IgnoreError::NumberStoredAsText),
+        Some(&vec!["A1".to_string(), "A2".to_string()])
+    );
+    assert!(worksheet
+        .ignore_errors(IgnoreError::EvalError, "not a range")
+        .is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_group_rows_and_columns() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_group_rows.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut bold = Format::new();
+    bold.set_bold();
+    worksheet.set_row(3, 30.0, Some(&bold))?;
+    for row in 1..10 {
+        worksheet.write_number(row, 0, row.into(), None)?;
+    }
+    worksheet.write_formula(10, 0, "=SUM(A2:A10)", None)?;
+    worksheet.group_rows(1, 9, true)?;
+
+    unsafe {
+        for row in 1..10 {
+            let lxw_row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, row);
+            assert_eq!((*lxw_row).level, 1);
+            assert_eq!((*lxw_row).hidden, 1);
+        }
+        let lxw_row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, 3);
+        assert_eq!((*lxw_row).height, 30.0);
+        assert_eq!((*lxw_row).format, workbook.get_internal_format(&bold)?);
+        let summary = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, 10);
+        assert_eq!((*summary).level, 0);
+        assert_eq!((*summary).collapsed, 1);
+        assert_eq!((*worksheet.worksheet).outline_row_level, 1);
+    }
+
+    worksheet.set_column(1, 3, 12.0, None)?;
+    worksheet.group_columns(1, 3, false)?;
+    worksheet.group_columns(2, 2, false)?;
+    {
+        let state = workbook.worksheet_state(worksheet.worksheet);
+        assert_eq!(state.columns[&1].width, ColumnWidth::Width(12.0));
+        assert_eq!(
+            state.columns[&1].options,
+            RowColOptions::new(false, 1, false)
+        );
+        assert_eq!(
+            state.columns[&2].options,
+            RowColOptions::new(false, 2, false)
+        );
+        assert!(!state.columns.contains_key(&4));
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_group_rows_collapsed_summary() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_group_rows-collapsed.xlsx")?;
+    let row = |worksheet: &Worksheet, row| unsafe {
+        let lxw_row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, row);
+        ((*lxw_row).level, (*lxw_row).hidden, (*lxw_row).collapsed)
+    };
+
+    // A collapsed top-level group with a nested group, summary rows below
+    let mut below = workbook.add_worksheet(None)?;
+    below.group_rows(3, 5, false)?;
+    below.group_rows(1, 8, true)?;
+    assert_eq!(row(&below, 1), (1, 1, 0));
+    assert_eq!(row(&below, 4), (2, 1, 0));
+    assert_eq!(row(&below, 9), (0, 0, 1));
+
+    // Summary rows and columns above and left of the groups
+    let mut above = workbook.add_worksheet(None)?;
+    above.outline_settings(true, false, false, false);
+    above.write_string(0, 0, "Total", None)?;
+    above.group_rows(1, 4, true)?;
+    assert_eq!(row(&above, 0), (0, 0, 1));
+    assert!(unsafe { libxlsxwriter_sys::lxw_worksheet_find_row(above.worksheet, 5) }.is_null());
+    above.group_columns(2, 3, true)?;
+    {
+        let state = workbook.worksheet_state(above.worksheet);
+        assert_eq!(
+            state.columns[&1].options,
+            RowColOptions::new(false, 0, true)
+        );
+        assert!(!state.columns.contains_key(&4));
+    }
+    // A group starting at the first row has no summary row above it
+    above.group_rows(0, 0, true)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_ignore_errors_multi() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_ignore_errors_multi.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    for row in 0..10 {
+        worksheet.write_string(row, 0, &row.to_string(), None)?;
+        worksheet.write_formula(row, 1, &format!("=A{}*2", row + 1), None)?;
+    }
+    worksheet.write_formula(10, 1, "=A1*3", None)?;
+    worksheet.ignore_errors_multi(
+        &[IgnoreError::NumberStoredAsText, IgnoreError::FormulaDiffers],
+        "$A$1:$B$11",
+    )?;
+    {
+        let state = workbook.worksheet_state(worksheet.worksheet);
+        let expected = vec!["A1:B11".to_string()];
+        assert_eq!(
+            state.ignored_errors.get(&IgnoreError::NumberStoredAsText),
+            Some(&expected)
+        );
+        assert_eq!(
+            state.ignored_errors.get(&IgnoreError::FormulaDiffers),
+            Some(&expected)
+        );
+        assert_eq!(state.ignored_errors.len(), 2);
+    }
+    workbook.write_deferred_settings()?;
+    unsafe {
+        let ws = worksheet.worksheet;
+        assert_eq!(
+            std::ffi::CStr::from_ptr((*ws).ignore_number_stored_as_text).to_str(),
+            Ok("A1:B11")
+        );
+        assert_eq!(
+            std::ffi::CStr::from_ptr((*ws).ignore_formula_differs).to_str(),
+            Ok("A1:B11")
+        );
+    }
+    assert!(worksheet
+        .ignore_errors_multi(&[IgnoreError::EvalError], "A1:")
+        .is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_unicode_sheet_name_and_string() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-workbook-unicode.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(Some("売上"))?;
+    worksheet.write_string(0, 0, "café 🎉", None)?;
+    worksheet.write_string(1, 0, "日本語のテキスト", None)?;
+    worksheet.write_comment(0, 0, "コメント ✔")?;
+    assert!(workbook.get_worksheet("売上")?.is_some());
+
+    // The sheet name limit is 31 characters, not 31 bytes.
+    let long_name: String = std::iter::repeat('表').take(31).collect();
+    workbook.add_worksheet(Some(&long_name))?;
+    let too_long_name: String = std::iter::repeat('表').take(32).collect();
+    assert!(workbook.add_worksheet(Some(&too_long_name)).is_err());
+
+    assert!(workbook.add_worksheet(Some("bad\0name")).is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_worksheet_name_errors() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-workbook-worksheet_name_errors.xlsx")?;
+    workbook.add_worksheet(Some("Sales"))?;
+    let too_long = "A".repeat(32);
+    for (name, reason) in [
+        ("", WorksheetNameError::Empty),
+        (too_long.as_str(), WorksheetNameError::TooLong),
+        ("Q1/Q2", WorksheetNameError::InvalidCharacter('/')),
+        ("[Draft]", WorksheetNameError::InvalidCharacter('[')),
+        ("'Sales", WorksheetNameError::Apostrophe),
+        ("Sales'", WorksheetNameError::Apostrophe),
+        ("history", WorksheetNameError::Reserved),
+        ("Sales", WorksheetNameError::AlreadyUsed),
+    ] {
+        let error = workbook.add_worksheet(Some(name)).unwrap_err();
+        assert_eq!(error.worksheet_name_error(), Some((name, reason)));
+    }
+    let error = workbook.add_worksheet(Some("a:b")).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Invalid worksheet name \"a:b\": the character ':' is not allowed"
+    );
+    assert_eq!(
+        XlsxError::new(libxlsxwriter_sys::lxw_error_LXW_ERROR_MEMORY_MALLOC_FAILED)
+            .worksheet_name_error(),
+        None
+    );
+    workbook.add_worksheet(Some("Sales 'draft'"))?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_tab_color_theme() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_set_tab_color-theme.xlsx")?;
+    let mut worksheet1 = workbook.add_worksheet(None)?;
+    worksheet1.set_tab_color(FormatColor::theme(ThemeColor::Accent2));
+    let mut worksheet2 = workbook.add_worksheet(None)?;
+    worksheet2.set_tab_color(ThemeColor::Dark1.into());
+    let mut worksheet3 = workbook.add_worksheet(None)?;
+    worksheet3.set_tab_color(FormatColor::Custom(0x12_34_56));
+
+    assert_eq!(FormatColor::theme(ThemeColor::Accent2).value(), 0xC0_50_4D);
+    assert_eq!(
+        FormatColor::theme(ThemeColor::Dark1).value(),
+        FormatColor::Black.value()
+    );
+    assert_eq!(FormatColor::Custom(0xFF_12_34_56).value(), 0x12_34_56);
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_used_range() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_used_range-2.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    assert_eq!(worksheet.used_range(), None);
+
+    worksheet.write_string(4, 3, "first", None)?;
+    assert_eq!(worksheet.used_range(), Some(CellRange::new(4, 3, 4, 3)));
+    assert_eq!(worksheet.used_range().unwrap().to_a1(), "D5");
+
+    worksheet.write_number(10, 1, 1.0, None)?;
+    worksheet.write_formula(2, 5, "=B11*2", None)?;
+    worksheet.write_boolean(7, 2, true, None)?;
+    let used = worksheet.used_range().unwrap();
+    assert_eq!(used, CellRange::new(2, 1, 10, 5));
+    assert_eq!(used.to_a1(), "B3:F11");
+    assert_eq!(used.to_string(), "B3:F11");
+
+    worksheet.add_table(
+        used.first_row,
+        used.first_col,
+        used.last_row,
+        used.last_col,
+        None,
+    )?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_write_link() -> Result<(), XlsxError> {
+    assert_eq!(
+        link_url("jane.doe@example.com"),
+        "mailto:jane.doe@example.com"
+    );
+    assert_eq!(
+        link_url("mailto:jane@example.com"),
+        "mailto:jane@example.com"
+    );
+    assert_eq!(
+        link_url("https://example.com/a@b"),
+        "https://example.com/a@b"
+    );
+    assert_eq!(link_url("www.example.com"), "http://www.example.com");
+    assert_eq!(link_url("internal:Sheet2!A1"), "internal:Sheet2!A1");
+    assert_eq!(link_url("not an@email"), "not an@email");
+
+    let workbook = Workbook::new("test-worksheet_write_link-2.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.write_link(0, 0, "jane.doe@example.com", None, None)?;
+    worksheet.write_link(1, 0, "https://www.rust-lang.org", Some("Rust"), None)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_formula_escaping() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_formula-escaping.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.write_string(0, 0, "a,b", None)?;
+    worksheet.write_formula(0, 1, r#"=IF(A1="a,b","yes","no")"#, None)?;
+    worksheet.write_formula_str(1, 1, r#"=IF(A1="a,b","yes","no")"#, None, "yes")?;
+    worksheet.write_formula(2, 1, r#"="quote "" and comma, ; <&> ü""#, None)?;
+    worksheet.write_formula(3, 1, r#"=CONCATENATE("'",A1,"'")"#, None)?;
+    worksheet.write_array_formula(4, 1, 4, 1, r#"{=SUM(IF(A1:A3="a,b",1,0))}"#, None)?;
+
+    // Long formulas are passed through without truncation.
+    let terms: Vec<String> = (1..=1000).map(|i| format!("A{}", i)).collect();
+    let long_formula = format!("=SUM({})", terms.join(","));
+    assert!(long_formula.len() > 4000);
+    worksheet.write_formula(5, 1, &long_formula, None)?;
+
+    assert!(worksheet
+        .write_formula(6, 1, "=\"nul\0byte\"", None)
+        .is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_workbook_hyperlink_base() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-workbook-hyperlink_base.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    workbook.set_properties(&DocProperties {
+        title: Some("Monthly report".to_string()),
+        hyperlink_base: Some("https://intranet.example.com/reports/".to_string()),
+        ..DocProperties::default()
+    })?;
+    worksheet.write_url(0, 0, "external:2024/summary.xlsx", None)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_workbook_set_metadata() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-workbook-set_metadata.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.write_string(0, 0, "Metadata", None)?;
+
+    let mut metadata = DocumentMetadata::new();
+    metadata
+        .set_title("Quarterly figures")
+        .set_author("Finance team")
+        .add_custom_property("Department", "Controlling")
+        .add_custom_property("Revision", 3);
+    assert_eq!(
+        metadata.properties,
+        DocProperties {
+            title: Some("Quarterly figures".to_string()),
+            author: Some("Finance team".to_string()),
+            ..DocProperties::default()
+        }
+    );
+    assert_eq!(
+        metadata.custom_properties,
+        vec![
+            (
+                "Department".to_string(),
+                CustomPropertyValue::String("Controlling".to_string())
+            ),
+            ("Revision".to_string(), CustomPropertyValue::Integer(3)),
+        ]
+    );
+    workbook.set_metadata(&metadata)?;
+
+    // Custom property names are limited to 255 characters
+    let mut too_long = DocumentMetadata::new();
+    too_long.add_custom_property(&"x".repeat(256), true);
+    assert!(workbook.set_metadata(&too_long).is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_doc_properties_keywords() -> Result<(), XlsxError> {
+    let mut properties = DocProperties::new();
+    properties.keywords(&["sales", "2024", "quarterly"]);
+    assert_eq!(
+        properties.keywords.as_deref(),
+        Some("sales; 2024; quarterly")
+    );
+    properties.keywords(&["single"]);
+    assert_eq!(properties.keywords.as_deref(), Some("single"));
+
+    let workbook = Workbook::new("test-workbook-doc_properties_keywords.xlsx")?;
+    workbook.set_properties(&properties)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_workbook_default_page_setup() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-workbook-default_page_setup.xlsx")?;
+    let before = workbook.add_worksheet(Some("Before"))?;
+    workbook.set_default_page_setup(&PageSetup {
+        landscape: true,
+        paper: Some(PaperType::A4),
+        ..PageSetup::new()
+    });
+    let after = workbook.add_worksheet(Some("After"))?;
+    let also_after = workbook.add_worksheet(None)?;
+    unsafe {
+        assert_eq!((*before.worksheet).orientation, 1);
+        assert_eq!((*after.worksheet).orientation, 0);
+        assert_eq!((*after.worksheet).paper_size, 9);
+        assert_eq!((*also_after.worksheet).orientation, 0);
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_workbook_add_worksheet_with() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-workbook-add_worksheet_with.xlsx")?;
+    let mut header_format = Format::new();
+    header_format.set_bold();
+    let setup = |worksheet: &mut Worksheet| {
+        worksheet.write_row(
+            0,
+            0,
+            &["Date".into(), "Amount".into()],
+            Some(&header_format),
+        )?;
+        worksheet.freeze_panes(1, 0);
+        worksheet.autofilter(0, 0, 0, 1)
+    };
+    let sheets = [
+        workbook.add_worksheet_with(Some("January"), setup)?,
+        workbook.add_worksheet_with(Some("February"), setup)?,
+        workbook.add_worksheet_with(None, setup)?,
+    ];
+    for worksheet in sheets.iter() {
+        unsafe {
+            let panes = (*worksheet.worksheet).panes;
+            assert_eq!(
+                panes.type_,
+                libxlsxwriter_sys::lxw_pane_types_FREEZE_PANES as u8
+            );
+            assert_eq!((panes.first_row, panes.first_col), (1, 0));
+            let autofilter = (*worksheet.worksheet).autofilter;
+            assert_eq!((autofilter.in_use, autofilter.last_col), (1, 1));
+            let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, 0);
+            let cell = libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, 1);
+            assert_eq!(
+                (*cell).format,
+                workbook.get_internal_format(&header_format)?
+            );
+        }
+    }
+    assert!(workbook.get_worksheet("Sheet3")?.is_some());
+
+    let failing = workbook.add_worksheet_with(Some("Failing"), |worksheet| {
+        worksheet.write_string(1_048_576, 0, "out of range", None)
+    });
+    assert!(failing.is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_workbook_add_worksheet_from_template() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-workbook-add_worksheet_from_template.xlsx")?;
+    let mut template = SheetTemplate::new();
+    template.column_widths = vec![(0, 0, 12.0), (1, 2, 18.0)];
+    template.headers = vec!["Date".to_string(), "Sales".to_string()];
+    template.header_format.set_bold();
+    template.freeze_panes = Some((1, 1));
+    template.page_setup = Some(PageSetup {
+        landscape: true,
+        ..PageSetup::new()
+    });
+    let sheets = [
+        workbook.add_worksheet_from_template(Some("January"), &template)?,
+        workbook.add_worksheet_from_template(Some("February"), &template)?,
+    ];
+    for worksheet in sheets.iter() {
+        {
+            let state = workbook.worksheet_state(worksheet.worksheet);
+            assert_eq!(state.columns[&0].width, ColumnWidth::Width(12.0));
+            assert_eq!(state.columns[&2].width, ColumnWidth::Width(18.0));
+            assert!(!state.columns.contains_key(&3));
+        }
+        assert!(worksheet.is_landscape());
+        unsafe {
+            let panes = (*worksheet.worksheet).panes;
+            assert_eq!(
+                panes.type_,
+                libxlsxwriter_sys::lxw_pane_types_FREEZE_PANES as u8
+            );
+            assert_eq!((panes.first_row, panes.first_col), (1, 1));
+            let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, 0);
+            let cell = libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, 1);
+            assert_eq!(
+                (*cell).format,
+                workbook.get_internal_format(&template.header_format)?
+            );
+        }
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_format_quote_prefix() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-format-quote_prefix.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut quoted = Format::new();
+    quoted.set_quote_prefix();
+    worksheet.write_string(0, 0, "00123", Some(&quoted))?;
+
+    let internal = workbook.get_internal_format(&quoted)?;
+    unsafe {
+        assert_eq!((*internal).quote_prefix, 1);
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, 0);
+        let cell = libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, 0);
+        assert_eq!(
+            u32::from((*cell).type_),
+            libxlsxwriter_sys::cell_types_STRING_CELL
+        );
+        assert_eq!((*cell).format, internal);
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_workbook_close_with_progress() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-workbook-close_with_progress.xlsx")?;
+    for _ in 0..3 {
+        let mut worksheet = workbook.add_worksheet(None)?;
+        for row in 0..1000 {
+            worksheet.write_number(row, 0, row.into(), None)?;
+        }
+    }
+    let mut events = Vec::new();
+    workbook.close_with_progress(|progress| events.push(progress))?;
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0], CloseProgress::Started { worksheets: 3 });
+    assert!(matches!(events[1], CloseProgress::Finished { .. }));
+    Ok(())
+}
+
+#[test]
+fn test_workbook_constant_memory() -> Result<(), XlsxError> {
+    let options = WorkbookOptions {
+        constant_memory: true,
+        tmpdir: Some(std::env::temp_dir().to_string_lossy().into_owned()),
+        use_zip64: false,
+    };
+    let workbook = Workbook::new_with_options("test-workbook-constant_memory.xlsx", &options)?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    for row in 0..100_000 {
+        worksheet.write_number(row, 0, row.into(), None)?;
+        worksheet.write_string(row, 1, "constant memory", None)?;
+    }
+    assert_eq!(
+        worksheet.used_range(),
+        Some(CellRange::new(0, 0, 99_999, 1))
+    );
+    workbook.close()?;
+
+    let metadata = std::fs::metadata("test-workbook-constant_memory.xlsx")?;
+    assert!(metadata.len() > 0);
+    Ok(())
+}
+
+#[test]
+fn test_format_scientific() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-format-scientific-2.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let scientific = Format::scientific();
+    assert_eq!(scientific, Format::new().set_num_format("0.00E+00").clone());
+    worksheet.write_number(0, 0, 1.23e10, Some(&scientific))?;
+    worksheet.write_number(1, 0, 6.022e-23, Some(&scientific))?;
+    worksheet.write_number(2, 0, f64::MAX, Some(&scientific))?;
+
+    let mut bold_scientific = Format::scientific();
+    bold_scientific.set_bold();
+    worksheet.write_number(3, 0, -4.5e100, Some(&bold_scientific))?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_set_column_format_keeps_width() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_set_column_format-2.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.set_column(0, 1, 30.0, None)?;
+    worksheet.set_column_pixels(3, 3, 120, None)?;
+
+    let mut format = Format::new();
+    format.set_bg_color(FormatColor::Yellow);
+    worksheet.set_column_format(0, 4, Some(&format))?;
+
+    let state = workbook.worksheet_state(worksheet.worksheet);
+    assert_eq!(state.columns[&0].width, ColumnWidth::Width(30.0));
+    assert_eq!(state.columns[&1].width, ColumnWidth::Width(30.0));
+    assert_eq!(
+        state.columns[&2].width,
+        ColumnWidth::Width(LXW_DEF_COL_WIDTH)
+    );
+    assert_eq!(state.columns[&3].width, ColumnWidth::Pixels(120));
+    assert_eq!(
+        state.columns[&4].width,
+        ColumnWidth::Width(LXW_DEF_COL_WIDTH)
+    );
+    drop(state);
+
+    worksheet.write_string(0, 0, "colored", None)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_set_column_num_format() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_set_column_num_format.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.set_column(1, 1, 14.0, None)?;
+    worksheet.set_column_num_format(1, "$#,##0.00")?;
+    for (row, amount) in [1250.0, 99.5, 12.25].iter().enumerate() {
+        worksheet.write_number(row as WorksheetRow, 1, *amount, None)?;
+    }
+
+    let mut currency = Format::new();
+    currency.set_num_format("$#,##0.00");
+    unsafe {
+        assert_eq!(
+            *(*worksheet.worksheet).col_formats.add(1),
+            workbook.get_internal_format(&currency)?
+        );
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, 0);
+        let cell = libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, 1);
+        assert!((*cell).format.is_null());
+    }
+    assert_eq!(
+        workbook.worksheet_state(worksheet.worksheet).columns[&1].width,
+        ColumnWidth::Width(14.0)
+    );
+    workbook.close()?;
+    Ok(())
+}
+
+#[cfg(feature = "rgb")]
+#[test]
+fn test_format_color_from_rgb() -> Result<(), XlsxError> {
+    assert_eq!(
+        FormatColor::from(rgb::RGB8::new(0x12, 0x34, 0x56)),
+        FormatColor::Custom(0x12_34_56)
+    );
+    assert_eq!(
+        FormatColor::from(rgb::RGBA8::new(0xFF, 0x00, 0x80, 0x10)),
+        FormatColor::Custom(0xFF_00_80)
+    );
+    assert_eq!(
+        FormatColor::from(rgb::RGB8::new(0, 0, 0)).value(),
+        FormatColor::Black.value()
+    );
+
+    let workbook = Workbook::new("test-format-color-rgb.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.write_string(
+        0,
+        0,
+        "rgb",
+        Some(Format::new().set_font_color(rgb::RGB8::new(0x4F, 0x81, 0xBD).into())),
+    )?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_set_column_and_row_scale() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_set_column_scale.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.set_column_scale(0, 1, 2.0, None)?;
+    worksheet.set_row_scale(0, 2.0, None)?;
+
+    let state = workbook.worksheet_state(worksheet.worksheet);
+    assert_eq!(
+        state.columns[&0].width,
+        ColumnWidth::Width(LXW_DEF_COL_WIDTH * 2.0)
+    );
+    assert_eq!(
+        state.columns[&1].width,
+        ColumnWidth::Width(LXW_DEF_COL_WIDTH * 2.0)
+    );
+    assert_eq!(state.column_pixels(0), 2 * LXW_DEF_COL_WIDTH_PIXELS - 5);
+    assert_eq!(state.row_pixels(0), 2 * LXW_DEF_ROW_HEIGHT_PIXELS);
+    drop(state);
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_fit_to_one_page() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_fit_to_one_page.xlsx")?;
+    let mut wide = workbook.add_worksheet(None)?;
+    for col in 0..20 {
+        wide.write_number(0, col, col.into(), None)?;
+        wide.write_number(1, col, col.into(), None)?;
+    }
+    wide.fit_to_one_page();
+    let mut tall = workbook.add_worksheet(None)?;
+    for row in 0..40 {
+        tall.write_number(row, 0, row.into(), None)?;
+    }
+    tall.fit_to_one_page();
+    unsafe {
+        assert_eq!((*wide.worksheet).orientation, 0);
+        assert_eq!((*wide.worksheet).fit_width, 1);
+        assert_eq!((*wide.worksheet).fit_height, 1);
+        assert_eq!((*tall.worksheet).orientation, 1);
+        assert_eq!((*tall.worksheet).fit_width, 1);
+        assert_eq!((*tall.worksheet).fit_height, 1);
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_insert_image_in_range() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_insert_image_in_range-2.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.set_column(2, 2, 20.0, None)?;
+    worksheet.set_row_pixels(4, 40, None)?;
+
+    let state = workbook.worksheet_state(worksheet.worksheet);
+    // B:D is 64 + 145 + 64 pixels, rows 2 to 10 are 8 * 20 + 40 pixels
+    assert_eq!(state.range_pixels(1, 1, 9, 3), (273, 200));
+    drop(state);
+
+    worksheet.insert_image_in_range(1, 1, 9, 3, "../images/simple1.png")?;
+    assert!(worksheet
+        .insert_image_in_range(1, 1, 9, 3, "../images/does-not-exist.png")
+        .is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_chart_axis_gridlines() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-chart_axis-gridlines-2.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    for i in 0..24 {
+        worksheet.write_number(i, 0, f64::from(i * 3 % 7), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Line);
+    chart.add_series(None, Some("=Sheet1!$A$1:$A$24"))?;
+    let mut y_axis = chart.y_axis();
+    y_axis.set_major_gridlines(true);
+    y_axis.set_major_unit(2.);
+    let mut x_axis = chart.x_axis();
+    x_axis.set_interval_unit(3);
+    x_axis.set_interval_tick(3);
+    unsafe {
+        assert_eq!((*y_axis.axis).major_gridlines.visible, 1);
+        assert_eq!((*x_axis.axis).interval_unit, 3);
+    }
+    worksheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_chart_date_category_axis() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-chart_axis-date_category-2.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut date_format = Format::new();
+    date_format.set_num_format("yyyy-mm-dd");
+    for i in 0..24 {
+        let date = DateTime::date(2020 + (i / 12) as i16, (i % 12) as i8 + 1, 1);
+        worksheet.write_datetime(i, 0, &date, Some(&date_format))?;
+        worksheet.write_number(i, 1, f64::from(i * 5), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Line);
+    chart.add_series(Some("=Sheet1!$A$1:$A$24"), Some("=Sheet1!$B$1:$B$24"))?;
+    let mut x_axis = chart.x_axis();
+    x_axis.set_num_format("mmm-yy")?;
+    x_axis.set_interval_unit(3);
+    worksheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_write_rich_string_validation() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_rich_string-validation.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut bold = Format::new();
+    bold.set_bold();
+
+    worksheet.write_rich_string(0, 0, &[("This is ", None), ("bold", Some(&bold))], None)?;
+    let error = worksheet
+        .write_rich_string(1, 0, &[("This is ", None), ("", Some(&bold))], None)
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Invalid rich string: fragment 1 is empty"
+    );
+    assert!(worksheet.write_rich_string(2, 0, &[], None).is_err());
+    let long = "a".repeat(20000);
+    assert!(worksheet
+        .write_rich_string(3, 0, &[(&long, None), (&long, Some(&bold))], None)
+        .is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_write_rich_string_formats() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_rich_string-formats.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut bold_red = Format::new();
+    bold_red.set_bold().set_font_color(FormatColor::Red);
+    let mut italic = Format::new();
+    italic.set_italic();
+    let mut wrap = Format::new();
+    wrap.set_text_wrap();
+
+    worksheet.write_rich_string(
+        0,
+        0,
+        &[
+            ("Price: ", None),
+            ("$20", Some(&bold_red)),
+            (" (on sale)", Some(&italic)),
+        ],
+        Some(&wrap),
+    )?;
+    unsafe {
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, 0);
+        let cell = libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, 0);
+        assert_eq!(
+            u32::from((*cell).type_),
+            libxlsxwriter_sys::cell_types_STRING_CELL
+        );
+        assert_eq!((*cell).format, workbook.get_internal_format(&wrap)?);
+    }
+
+    let error = worksheet
+        .write_rich_string(1, 0, &[("a\0b", Some(&italic))], None)
+        .unwrap_err();
+    assert!(matches!(error.source, XlsxErrorSource::NulError(_)));
+    workbook.close()?;
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_write_chrono_datetime() -> Result<(), XlsxError> {
+    use chrono::{FixedOffset, TimeZone, Utc};
+
+    let workbook = Workbook::new("test-worksheet_write_chrono_datetime.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut date_format = Format::new();
+    date_format.set_num_format("yyyy-mm-dd hh:mm");
+
+    let utc = Utc.with_ymd_and_hms(2024, 3, 10, 9, 30, 0).unwrap();
+    let tokyo = FixedOffset::east_opt(9 * 3600)
+        .unwrap()
+        .with_ymd_and_hms(2024, 3, 10, 18, 30, 0)
+        .unwrap();
+    worksheet.write_chrono_datetime_utc(0, 0, &utc, Some(&date_format))?;
+    worksheet.write_chrono_datetime_local(0, 1, &utc, Some(&date_format))?;
+    worksheet.write_chrono_datetime_utc(1, 0, &tokyo, Some(&date_format))?;
+    worksheet.write_chrono_datetime_local(1, 1, &tokyo, Some(&date_format))?;
+
+    let number = |row, col| unsafe {
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, row);
+        let cell = libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, col);
+        (*cell).u.number
+    };
+    // 2024-03-10 09:30 and 18:30 as Excel serial dates
+    let morning = 45361.0 + 9.5 / 24.0;
+    let evening = 45361.0 + 18.5 / 24.0;
+    assert!((number(0, 0) - morning).abs() < 1e-9);
+    assert!((number(0, 1) - morning).abs() < 1e-9);
+    assert!((number(1, 0) - morning).abs() < 1e-9);
+    assert!((number(1, 1) - evening).abs() < 1e-9);
+    workbook.close()?;
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_write_date_series() -> Result<(), XlsxError> {
+    use chrono::NaiveDate;
+
+    let workbook = Workbook::new("test-worksheet_write_date_series.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let start = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+    worksheet.write_date_series(1, 0, start, 7, 1, None)?;
+    worksheet.write_date_series(1, 1, start, 3, -7, None)?;
+
+    let number = |row, col| unsafe {
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, row);
+        let cell = libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, col);
+        (*cell).u.number
+    };
+    // 2024-03-10 is 45361 as an Excel serial date
+    for i in 0..7 {
+        assert_eq!(number(i + 1, 0), 45361.0 + f64::from(i));
+    }
+    assert_eq!(number(3, 1), 45361.0 - 14.0);
+    unsafe {
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, 8);
+        assert!(libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, 0).is_null());
+    }
+
+    assert!(worksheet
+        .write_date_series(1_048_570, 2, start, 7, 1, None)
+        .is_err());
+    assert!(worksheet
+        .write_date_series(0, 3, start, 2, i64::MAX, None)
+        .is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_write_date() -> Result<(), XlsxError> {
+    use chrono::NaiveDate;
+
+    let workbook = Workbook::new("test-worksheet_write_date.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let date = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+    let mut long_date = Format::new();
+    long_date.set_num_format("d mmmm yyyy");
+    worksheet.write_date(0, 0, date, None)?;
+    worksheet.write_date(1, 0, date, Some(&long_date))?;
+
+    let mut default_format = Format::new();
+    default_format.set_num_format("yyyy-mm-dd");
+    unsafe {
+        let cell = |row| {
+            let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, row);
+            libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, 0)
+        };
+        assert_eq!((*cell(0)).u.number, 45361.0);
+        assert_eq!(
+            (*cell(0)).format,
+            workbook.get_internal_format(&default_format)?
+        );
+        assert_eq!((*cell(1)).format, workbook.get_internal_format(&long_date)?);
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_add_signed_vba_project() -> Result<(), XlsxError> {
+    let dir = std::env::temp_dir();
+    let project = dir.join("xlsxwriter-rs-vbaProject.bin");
+    let signature = dir.join("xlsxwriter-rs-vbaProjectSignature.bin");
+    std::fs::write(&project, b"vba project")?;
+    std::fs::write(&signature, b"vba signature")?;
+
+    let mut workbook = Workbook::new("test-workbook-add_signed_vba_project.xlsm")?;
+    workbook.add_worksheet(None)?;
+    assert!(workbook
+        .add_signed_vba_project(project.to_str().unwrap(), "no-such-signature.bin")
+        .is_err());
+    workbook.add_signed_vba_project(project.to_str().unwrap(), signature.to_str().unwrap())?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_set_vba_name_validation() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_set_vba_name-validation.xlsm")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.set_vba_name("Sheet_1")?;
+    let error = worksheet.set_vba_name("My Sheet").unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("Invalid VBA name: \"My Sheet\""));
+    assert!(worksheet.set_vba_name("1Sheet").is_err());
+    assert!(worksheet.set_vba_name("").is_err());
+    assert!(worksheet.set_vba_name(&"A".repeat(32)).is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_chart_series_labels_and_trendline() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-chart_series-labels_trendline.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        worksheet.write_number(i, 0, (i * 10 + 2).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    let mut series = chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    series.set_labels_options(true, false, false);
+    series.set_trendline(ChartTrendlineType::Linear, 2);
+    unsafe {
+        let series = series.chart_series;
+        assert_eq!((*series).has_labels, 1);
+        assert_eq!((*series).show_labels_value, 1);
+        assert_eq!((*series).show_labels_category, 0);
+        assert_eq!((*series).show_labels_name, 0);
+        assert_eq!((*series).has_trendline, 1);
+        assert_eq!((*series).trendline_type, ChartTrendlineType::Linear.value());
+        assert_eq!((*series).trendline_value, 0);
+    }
+    worksheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_chart_add_series_from_slice() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-chart-add_series_from_slice.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut chart = workbook.add_chart(ChartType::Line);
+    let first = chart.add_series_from_slice(&[1.0, 4.0, 9.0])?;
+    let second = chart.add_series_from_slice(&[2.0, 3.0])?;
+    assert!(chart.add_series_from_slice(&[]).is_err());
+    worksheet.insert_chart(1, 1, &chart)?;
+
+    let data = workbook.get_worksheet("_ChartData")?.unwrap();
+    unsafe {
+        assert_eq!((*data.worksheet).hidden, 1);
+        let values = (*first.chart_series).values;
+        assert_eq!(
+            (
+                (*values).first_row,
+                (*values).first_col,
+                (*values).last_row,
+                (*values).last_col
+            ),
+            (0, 0, 2, 0)
+        );
+        let values = (*second.chart_series).values;
+        assert_eq!(
+            (
+                (*values).first_row,
+                (*values).first_col,
+                (*values).last_row,
+                (*values).last_col
+            ),
+            (0, 1, 1, 1)
+        );
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(data.worksheet, 2);
+        let cell = libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, 0);
+        assert_eq!((*cell).u.number, 9.0);
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_insert_chart_twice() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_insert_chart-twice.xlsx")?;
+    let mut worksheet1 = workbook.add_worksheet(None)?;
+    let mut worksheet2 = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        worksheet1.write_number(i, 0, f64::from(i), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    worksheet1.insert_chart(1, 3, &chart)?;
+
+    let error = worksheet2.insert_chart(1, 3, &chart).unwrap_err();
+    assert_eq!(error.source, XlsxErrorSource::ChartAlreadyInserted);
+    assert!(worksheet1.insert_chart(20, 3, &chart).is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_format_custom_colors() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-format-custom_colors.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut format = Format::new();
+    format
+        .set_font_color(FormatColor::Custom(0x123456))
+        .set_pattern(FormatPatterns::DarkGrid)
+        .set_bg_color(FormatColor::Custom(0x234567))
+        .set_fg_color(FormatColor::Custom(0x345678))
+        .set_border(FormatBorder::Thin)
+        .set_border_color(FormatColor::Custom(0x456789));
+    worksheet.write_string(0, 0, "colors", Some(&format))?;
+
+    let internal = workbook.get_internal_format(&format)?;
+    unsafe {
+        assert_eq!((*internal).font_color, 0x123456);
+        assert_eq!((*internal).bg_color, 0x234567);
+        assert_eq!((*internal).fg_color, 0x345678);
+        assert_eq!((*internal).bottom_color, 0x456789);
+        assert_eq!((*internal).top_color, 0x456789);
+        assert_eq!((*internal).left_color, 0x456789);
+        assert_eq!((*internal).right_color, 0x456789);
+    }
+
+    let mut sides = Format::new();
+    sides
+        .set_border(FormatBorder::Thin)
+        .set_border_bottom_color(FormatColor::Custom(0x010203))
+        .set_border_top_color(FormatColor::Custom(0x040506))
+        .set_border_left_color(FormatColor::Custom(0x070809))
+        .set_border_right_color(FormatColor::Custom(0x0A0B0C));
+    worksheet.write_string(1, 0, "sides", Some(&sides))?;
+    let internal = workbook.get_internal_format(&sides)?;
+    unsafe {
+        assert_eq!((*internal).bottom_color, 0x010203);
+        assert_eq!((*internal).top_color, 0x040506);
+        assert_eq!((*internal).left_color, 0x070809);
+        assert_eq!((*internal).right_color, 0x0A0B0C);
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_write_currency() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_currency-2.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.write_currency(0, 0, 1234.56, Currency::Usd)?;
+    worksheet.write_currency(1, 0, 1234.56, Currency::Eur)?;
+
+    for (currency, expected) in [
+        (Currency::Usd, "[$$-409]#,##0.00"),
+        (Currency::Eur, "#,##0.00 [$€-407]"),
+    ] {
+        let internal = workbook.get_internal_format(&Format::currency(currency))?;
+        let num_format = unsafe { std::ffi::CStr::from_ptr((*internal).num_format.as_ptr()) };
+        assert_eq!(num_format.to_str().unwrap(), expected);
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_write_number_with_unit() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_number_with_unit.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.write_number_with_unit(0, 0, 3.5, "kg", 1)?;
+    worksheet.write_number_with_unit(1, 0, 12.0, "in\"", 0)?;
+
+    for (decimals, unit, expected) in [
+        (1, "kg", "0.0\" kg\""),
+        (0, "in\"", "0\" in\"\\\""),
+        (3, "a\"b", "0.000\" a\"\\\"\"b\""),
+    ] {
+        let internal = workbook.get_internal_format(&Format::number_with_unit(decimals, unit))?;
+        let num_format = unsafe { std::ffi::CStr::from_ptr((*internal).num_format.as_ptr()) };
+        assert_eq!(num_format.to_str().unwrap(), expected);
+    }
+    unsafe {
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, 0);
+        let cell = libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, 0);
+        assert_eq!((*cell).u.number, 3.5);
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_format_number_with_thousands() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-format-number_with_thousands-2.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    for (i, (decimals, expected)) in [(0, "#,##0"), (2, "#,##0.00"), (3, "#,##0.000")]
+        .iter()
+        .enumerate()
+    {
+        let format = Format::number_with_thousands(*decimals);
+        worksheet.write_number(i as WorksheetRow, 0, 1234567.891, Some(&format))?;
+        let internal = workbook.get_internal_format(&format)?;
+        let num_format = unsafe { std::ffi::CStr::from_ptr((*internal).num_format.as_ptr()) };
+        assert_eq!(num_format.to_str().unwrap(), *expected);
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_format_accounting_red_negatives() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-format-accounting_red_negatives.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let format = Format::accounting_red_negatives();
+    worksheet.write_number(0, 0, 1234.5, Some(&format))?;
+    worksheet.write_number(1, 0, -1234.5, Some(&format))?;
+
+    let internal = workbook.get_internal_format(&format)?;
+    let num_format = unsafe { std::ffi::CStr::from_ptr((*internal).num_format.as_ptr()) };
+    let sections: Vec<_> = num_format.to_str().unwrap().split(';').collect();
+    assert_eq!(sections.len(), 2);
+    assert!(sections[1].starts_with("[Red]("));
+    assert!(sections[1].ends_with(')'));
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_set_column_default_format() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_set_column-default_format.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut column_format = Format::new();
+    column_format.set_bg_color(FormatColor::Yellow);
+
+    // Cells written before and after set_column both pick up the column format
+    worksheet.write_string(0, 1, "before", None)?;
+    worksheet.set_column(1, 1, 12.0, Some(&column_format))?;
+    worksheet.write_string(1, 1, "after", None)?;
+
+    let internal = workbook.get_internal_format(&column_format)?;
+    unsafe {
+        let sheet = worksheet.worksheet;
+        assert!((*sheet).col_formats_max > 1);
+        assert_eq!(*(*sheet).col_formats.add(1), internal);
+        assert!((*(*sheet).col_formats.add(0)).is_null());
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_set_row_default_format() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_set_row-default_format.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut row_format = Format::new();
+    row_format.set_bg_color(FormatColor::Yellow);
+
+    worksheet.write_string(2, 0, "before", None)?;
+    worksheet.set_row(2, LXW_DEF_ROW_HEIGHT, Some(&row_format))?;
+    worksheet.write_string(2, 1, "after", None)?;
+
+    let internal = workbook.get_internal_format(&row_format)?;
+    unsafe {
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, 2);
+        assert!(!row.is_null());
+        assert_eq!((*row).format, internal);
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_workbook_new_missing_directory() {
+    let error = Workbook::new("does-not-exist/test-workbook.xlsx")
+        .err()
+        .expect("missing directory should be rejected");
+    let message = error.to_string();
+    assert!(
+        message.contains("does-not-exist/test-workbook.xlsx"),
+        "{}",
+        message
+    );
+    assert!(
+        message.contains("cannot access directory does-not-exist"),
+        "{}",
+        message
+    );
+
+    assert!(Workbook::new_with_options(
+        "does-not-exist/test-workbook.xlsx",
+        &WorkbookOptions::new()
+    )
+    .is_err());
+}
+
+#[test]
+fn test_write_url_defined_name() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_url-defined_name.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut data = workbook.add_worksheet(Some("Data"))?;
+    data.write_number(4, 2, 10.0, None)?;
+    workbook.define_name("MyNamedRange", "=Data!$C$5:$C$10")?;
+
+    worksheet.write_url(0, 0, "internal:MyNamedRange", None)?;
+    worksheet.write_url_opt(1, 0, "internal:mynamedrange", None, Some("Go"), None)?;
+    worksheet.write_url(2, 0, "internal:Data!A1", None)?;
+    worksheet.write_url(3, 0, "internal:B2", None)?;
+    worksheet.write_url(4, 0, "internal:A:A", None)?;
+    worksheet.write_url(5, 0, "internal:$1:$3", None)?;
+    // Names can be defined after the link
+    worksheet.write_url(6, 0, "internal:Totals", None)?;
+    workbook.define_name("Totals", "=Data!$D$1")?;
+    let error = worksheet
+        .write_url(7, 0, "internal:Sales Q1", None)
+        .unwrap_err();
+    assert_eq!(
+        error.source,
+        XlsxErrorSource::InvalidInternalLink("Sales Q1".to_string())
+    );
+    assert!(worksheet.write_url(7, 0, "internal:A:1", None).is_err());
+
+    // Strings that can't be links are written as text
+    worksheet.set_autoconvert_urls(true);
+    worksheet.write_string(8, 0, "internal:#N/A", None)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_chart_series_name_from_cell() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-chart_series-set_name_range-2.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.write_string(0, 1, "Revenue", None)?;
+    for i in 1..6 {
+        worksheet.write_number(i, 1, f64::from(i * 10), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    let mut series = chart.add_series(None, Some("=Sheet1!$B$2:$B$6"))?;
+    series.set_name_range("Sheet1", 0, 1)?;
+    unsafe {
+        let range = (*series.chart_series).title.range;
+        assert_eq!(
+            std::ffi::CStr::from_ptr((*range).sheetname)
+                .to_str()
+                .unwrap(),
+            "Sheet1"
+        );
+        assert_eq!(((*range).first_row, (*range).first_col), (0, 1));
+    }
+    worksheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_chart_series_update_ranges() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-chart_series-update_ranges.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        worksheet.write_string(i, 0, &format!("item {}", i + 1), None)?;
+        worksheet.write_number(i, 1, f64::from(i), None)?;
+        worksheet.write_number(i, 2, f64::from(i * i), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    let mut series = chart.add_series(Some("=Sheet1!$A$1:$A$5"), Some("=Sheet1!$B$1:$B$5"))?;
+    series.set_values("Sheet1", 0, 2, 4, 2)?;
+    series.set_categories("Sheet1", 1, 0, 4, 0)?;
+    unsafe {
+        let values = (*series.chart_series).values;
+        assert_eq!(((*values).first_col, (*values).last_col), (2, 2));
+        let categories = (*series.chart_series).categories;
+        assert_eq!(((*categories).first_row, (*categories).last_row), (1, 4));
+    }
+    worksheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_chart_line_styles() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-chart-line_styles.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        worksheet.write_number(i, 0, f64::from(i * 7 % 5), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Line);
+    let mut series = chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    let mut line = ChartLine::new();
+    line.color = FormatColor::Red;
+    line.width = 2.0;
+    line.dash_type = ChartDashType::Dash;
+    series.set_line(&line);
+    chart.set_drop_lines(None);
+    unsafe {
+        let series_line = (*series.chart_series).line;
+        assert!(!series_line.is_null());
+        assert_eq!((*series_line).color, FormatColor::Red.value());
+        assert_eq!((*series_line).width, 2.0);
+        assert_eq!((*chart.chart).has_drop_lines, 1);
+    }
+    worksheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_chart_series_markers() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-chart_series-markers.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        worksheet.write_number(i, 0, f64::from(i), None)?;
+        worksheet.write_number(i, 1, f64::from(i * 7 % 5), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Scatter);
+    let mut series = chart.add_series(Some("=Sheet1!$A$1:$A$5"), Some("=Sheet1!$B$1:$B$5"))?;
+    series.set_marker_type(ChartMarkerType::MarkerDiamond);
+    series.set_marker_size(8);
+    let mut fill = ChartFill::new();
+    fill.color = FormatColor::Yellow;
+    series.set_marker_fill(&fill);
+    unsafe {
+        let marker = (*series.chart_series).marker;
+        assert!(!marker.is_null());
+        assert_eq!((*marker).type_, ChartMarkerType::MarkerDiamond.value());
+        assert_eq!((*marker).size, 8);
+        assert!(!(*marker).fill.is_null());
+    }
+    worksheet.insert_chart(1, 3, &chart)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_autoconvert_urls() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet-set_autoconvert_urls-2.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.write_string(0, 0, "https://github.com", None)?;
+    assert_eq!(unsafe { (*worksheet.worksheet).hlink_count }, 0);
+
+    worksheet.set_autoconvert_urls(true);
+    worksheet.write_string(1, 0, "https://github.com", None)?;
+    worksheet.write_string(2, 0, "see https://github.com", None)?;
+    assert_eq!(unsafe { (*worksheet.worksheet).hlink_count }, 1);
+
+    worksheet.set_autoconvert_urls(false);
+    worksheet.write_string(3, 0, "mailto:someone@example.com", None)?;
+    assert_eq!(unsafe { (*worksheet.worksheet).hlink_count }, 1);
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_string_url_conversion_many_strings() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-workbook-set_string_url_conversion-2.xlsx")?;
+    // With conversion on, the strings run into the hyperlink limit of Excel
+    workbook.set_string_url_conversion(true);
+    let mut linked = workbook.add_worksheet(None)?;
+    for i in 0..65530 {
+        linked.write_string(i, 0, &format!("https://example.com/{}", i), None)?;
+    }
+    assert_eq!(unsafe { (*linked.worksheet).hlink_count }, 65530);
+    let error = linked
+        .write_string(65530, 0, "https://example.com/65530", None)
+        .unwrap_err();
+    assert_eq!(error.source, XlsxErrorSource::TooManyHyperlinks(65530));
+
+    // Turning it off again writes them as plain strings
+    workbook.set_string_url_conversion(false);
+    let mut worksheet = workbook.add_worksheet(None)?;
+    for i in 0..70_000 {
+        worksheet.write_string(i, 0, &format!("https://example.com/{}", i), None)?;
+    }
+    assert_eq!(unsafe { (*worksheet.worksheet).hlink_count }, 0);
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_too_many_hyperlinks() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_url-too_many.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    for i in 0..65530 {
+        worksheet.write_url(i, 0, "https://example.com", None)?;
+    }
+    let error = worksheet
+        .write_url_opt(
+            65530,
+            0,
+            "https://example.com",
+            None,
+            Some("one more"),
+            None,
+        )
+        .unwrap_err();
+    assert_eq!(error.source, XlsxErrorSource::TooManyHyperlinks(65530));
+    assert!(worksheet
+        .write_file_link(65531, 0, "report.xlsx", None, None)
+        .is_err());
+    assert_eq!(unsafe { (*worksheet.worksheet).hlink_count }, 65530);
+
+    // The limit is per worksheet
+    let mut other = workbook.add_worksheet(None)?;
+    other.write_url(0, 0, "https://example.com", None)?;
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_write_url_tooltip_length() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_url_opt-tooltip_length.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let tooltip = "x".repeat(255);
+    worksheet.write_url_opt(0, 0, "https://example.com", None, None, Some(&tooltip))?;
+    let error = worksheet
+        .write_url_opt(
+            1,
+            0,
+            "https://example.com",
+            None,
+            None,
+            Some(&"x".repeat(256)),
+        )
+        .unwrap_err();
+    assert_eq!(error.source, XlsxErrorSource::TooltipTooLong(256));
+    assert_eq!(unsafe { (*worksheet.worksheet).hlink_count }, 1);
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_comment_start_cell() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_comment_opt-start_cell.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.write_number(1, 1, 42.0, None)?;
+    let mut options = CommentOptions::new();
+    options
+        .set_author("Reviewer")
+        .set_visible(CommentDisplayType::Visible)
+        .set_start_cell(1, 3);
+    worksheet.write_comment_opt(1, 1, "Comment box two columns to the right", &options)?;
+
+    let internal = options.to_internal(&workbook)?;
+    assert_eq!((internal.start_row, internal.start_col), (1, 3));
+    assert_eq!(internal.color, 0);
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_comment_newlines() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_comment-newlines.xlsx")?;
+    let comment = |worksheet: &Worksheet| unsafe {
+        // The only comment of the worksheet is the root of the comment tree
+        let row = (*(*worksheet.worksheet).comments).rbh_root;
+        let cell = (*(*row).cells).rbh_root;
+        std::ffi::CStr::from_ptr((*(*cell).comment).text)
+            .to_string_lossy()
+            .into_owned()
+    };
+
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.write_comment(0, 0, "First line\r\nSecond line\rThird line")?;
+    assert_eq!(comment(&worksheet), "First line\nSecond line\nThird line");
+
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.write_comment_opt(0, 0, "One\r\nTwo\nThree", &CommentOptions::new())?;
+    assert_eq!(comment(&worksheet), "One\nTwo\nThree");
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_comment_auto_size() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_comment_opt-auto_size.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut options = CommentOptions::new();
+    options.set_auto_size();
+    worksheet.write_comment_opt(0, 0, "Short", &options)?;
+    let long_text = "This comment explains the figure in some detail. ".repeat(8);
+    worksheet.write_comment_opt(1, 0, &long_text, &options)?;
+    worksheet.write_comment_opt(2, 0, "One\nTwo\nThree\nFour\nFive\nSix", &options)?;
+    options.set_width(200);
+    worksheet.write_comment_opt(3, 0, &long_text, &options)?;
+
+    let size = |row| unsafe {
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, row);
+        let cell = libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, 0);
+        let comment = (*cell).comment;
+        ((*comment).width, (*comment).height)
+    };
+    // A short comment keeps the default size
+    assert_eq!(size(0), (128, 74));
+    let (long_width, long_height) = size(1);
+    assert_eq!(long_width, 320);
+    assert!(long_height > 74);
+    let (lines_width, lines_height) = size(2);
+    assert_eq!(lines_width, 128);
+    assert!(lines_height > 74);
+    let (fixed_width, fixed_height) = size(3);
+    assert_eq!(fixed_width, 200);
+    assert!(fixed_height > long_height);
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_show_comments_before_comments() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_show_comments-before.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.show_comments();
+    worksheet.write_comment(0, 0, "Visible comment")?;
+    worksheet.write_comment(1, 0, "Another visible comment")?;
+
+    workbook.write_deferred_settings()?;
+    unsafe {
+        assert_eq!(
+            u32::from((*worksheet.worksheet).comment_display_default),
+            libxlsxwriter_sys::lxw_comment_display_types_LXW_COMMENT_DISPLAY_VISIBLE
+        );
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_write_row_and_column() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_row_and_column.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut border = Format::new();
+    border.set_border(FormatBorder::Thin);
+    let values: Vec<CellValue> = vec![
+        "Apples".into(),
+        12.into(),
+        true.into(),
+        CellValue::Blank,
+        CellValue::Formula("=B1*2".to_string()),
+    ];
+    worksheet.write_row(0, 0, &values, Some(&border))?;
+    worksheet.write_column(1, 0, &values, None)?;
+
+    let cell_type = |row, col| unsafe {
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, row);
+        let cell = libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, col);
+        u32::from((*cell).type_)
+    };
+    let expected = [
+        libxlsxwriter_sys::cell_types_STRING_CELL,
+        libxlsxwriter_sys::cell_types_NUMBER_CELL,
+        libxlsxwriter_sys::cell_types_BOOLEAN_CELL,
+        libxlsxwriter_sys::cell_types_BLANK_CELL,
+        libxlsxwriter_sys::cell_types_FORMULA_CELL,
+    ];
+    for (col, expected) in expected.iter().enumerate() {
+        assert_eq!(cell_type(0, col as WorksheetCol), *expected);
+    }
+    assert_eq!(cell_type(2, 0), libxlsxwriter_sys::cell_types_NUMBER_CELL);
+    assert_eq!(cell_type(5, 0), libxlsxwriter_sys::cell_types_FORMULA_CELL);
+    unsafe {
+        // A blank cell without a format is not written
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, 4);
+        assert!(libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, 0).is_null());
+    }
+
+    assert!(worksheet.write_row(1, 16_380, &values, None).is_err());
+    assert!(worksheet.write_column(1_048_572, 1, &values, None).is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_write_stats_block() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_stats_block.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let values = [2.0, 4.0, f64::NAN, 4.0, 6.0];
+    worksheet.write_stats_block(1, 1, &values, &StatsLabels::default())?;
+    worksheet.write_stats_block(1, 4, &[f64::NAN], &StatsLabels::default())?;
+
+    let cell = |row, col| unsafe {
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, row);
+        libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, col)
+    };
+    unsafe {
+        assert_eq!((*cell(1, 2)).u.number, 4.0);
+        assert_eq!((*cell(2, 2)).u.number, 4.0);
+        assert_eq!((*cell(3, 2)).u.number, 2.0);
+        assert_eq!((*cell(4, 2)).u.number, 6.0);
+        assert!(((*cell(5, 2)).u.number - (8.0f64 / 3.0).sqrt()).abs() < 1e-9);
+        assert_eq!(
+            u32::from((*cell(2, 1)).type_),
+            libxlsxwriter_sys::cell_types_STRING_CELL
+        );
+
+        // Only the count of no values is defined
+        assert_eq!((*cell(1, 5)).u.number, 0.0);
+        assert!(cell(2, 5).is_null());
+    }
+    assert!(worksheet
+        .write_stats_block(0, 16_383, &values, &StatsLabels::default())
+        .is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_cursor() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_cursor.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut cursor = worksheet.cursor(2, 1);
+    assert_eq!(cursor.position(), (2, 1));
+    cursor.write_row(&["Name".into(), "Score".into()], None)?;
+    assert_eq!(cursor.position(), (3, 1));
+    cursor.write_row(&["Alice".into(), 92.into()], None)?;
+    cursor.write(&"Bob".into(), None)?.write(&87.into(), None)?;
+    assert_eq!(cursor.position(), (4, 3));
+    cursor.newline();
+    assert_eq!((cursor.row(), cursor.col()), (5, 1));
+    assert!(cursor
+        .write_row(&vec![CellValue::Blank; 16_384], None)
+        .is_err());
+    assert_eq!(cursor.position(), (5, 1));
+
+    let cell_type = |row, col| unsafe {
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, row);
+        let cell = libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, col);
+        u32::from((*cell).type_)
+    };
+    for row in 2..5 {
+        assert_eq!(cell_type(row, 1), libxlsxwriter_sys::cell_types_STRING_CELL);
+    }
+    assert_eq!(cell_type(2, 2), libxlsxwriter_sys::cell_types_STRING_CELL);
+    assert_eq!(cell_type(3, 2), libxlsxwriter_sys::cell_types_NUMBER_CELL);
+    assert_eq!(cell_type(4, 2), libxlsxwriter_sys::cell_types_NUMBER_CELL);
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_write_file_link() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_file_link.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.write_file_link(0, 0, "..\\docs\\file.pdf", None, None)?;
+    worksheet.write_file_link(1, 0, "../docs/file.pdf", Some("Manual"), None)?;
+    let error = worksheet
+        .write_file_link(2, 0, "/home/user/file.pdf", None, None)
+        .unwrap_err();
+    assert_eq!(
+        error.source,
+        XlsxErrorSource::InvalidFileLink(
+            "/home/user/file.pdf".to_string(),
+            "absolute POSIX paths cannot be opened on Windows".to_string()
+        )
+    );
+    workbook.close()?;
+    Ok(())
+}
+
+#[cfg(feature = "formula-tracking")]
+#[test]
+fn test_last_written_formula() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_last_written_formula.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    assert_eq!(worksheet.last_written_formula(), None);
+
+    let sheet = worksheet.worksheet;
+    let stored_formula = |row, col| unsafe {
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(sheet, row);
+        let cell = libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, col);
+        std::ffi::CStr::from_ptr((*cell).u.string)
+            .to_str()
+            .unwrap()
+            .to_string()
+    };
+
+    worksheet.write_formula(0, 0, r#"=IF(B1="a,b","yes","no")"#, None)?;
+    assert_eq!(
+        worksheet.last_written_formula().as_deref(),
+        Some(r#"IF(B1="a,b","yes","no")"#)
+    );
+    assert_eq!(worksheet.last_written_formula(), Some(stored_formula(0, 0)));
+
+    worksheet.write_formula_num(1, 0, "1+2", None, 3.0)?;
+    assert_eq!(worksheet.last_written_formula(), Some(stored_formula(1, 0)));
+
+    worksheet.write_array_formula(2, 0, 2, 0, "{=SUM(B1:C1*B2:C2)}", None)?;
+    assert_eq!(
+        worksheet.last_written_formula().as_deref(),
+        Some("SUM(B1:C1*B2:C2)")
+    );
+    assert_eq!(worksheet.last_written_formula(), Some(stored_formula(2, 0)));
+
+    assert!(worksheet.write_formula(1_048_576, 0, "=1", None).is_err());
+    assert_eq!(
+        worksheet.last_written_formula().as_deref(),
+        Some("SUM(B1:C1*B2:C2)")
+    );
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_write_array_formula_num() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_array_formula_num.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.write_row(0, 0, &[1.into(), 2.into()], None)?;
+    worksheet.write_row(1, 0, &[3.into(), 4.into()], None)?;
+    let results = [vec![2., 4.], vec![6., 8.]];
+    worksheet.write_array_formula_num(0, 3, 1, 4, "{=A1:B2*2}", None, &results)?;
+    #[cfg(feature = "formula-tracking")]
+    assert_eq!(worksheet.last_written_formula().as_deref(), Some("A1:B2*2"));
+    let cell = |row, col| unsafe {
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, row);
+        libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, col)
+    };
+    unsafe {
+        assert_eq!((*cell(0, 3)).formula_result, 2.);
+        assert_eq!((*cell(0, 4)).u.number, 4.);
+        assert_eq!((*cell(1, 3)).u.number, 6.);
+        assert_eq!((*cell(1, 4)).u.number, 8.);
+    }
+    assert!(worksheet
+        .write_array_formula_num(3, 3, 4, 4, "{=A1:B2*2}", None, &[vec![2., 4.]])
+        .is_err());
+    assert!(worksheet
+        .write_array_formula_num(3, 3, 4, 4, "{=A1:B2*2}", None, &[vec![2.], vec![6.]])
+        .is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_rewrite_with_format() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_rewrite_with_format.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut bold = Format::new();
+    bold.set_bold();
+    let mut money = Format::new();
+    money.set_num_format("#,##0.00");
+    let cell = |worksheet: &Worksheet, row, col| unsafe {
+        let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, row);
+        libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, col)
+    };
+
+    worksheet.write_number(0, 0, 1234.5, Some(&bold))?;
+    worksheet.rewrite_with_format(0, 0, Some(&money))?;
+    unsafe {
+        let cell = cell(&worksheet, 0, 0);
+        assert_eq!((*cell).u.number, 1234.5);
+        assert_eq!((*cell).format, workbook.get_internal_format(&money)?);
+    }
+    worksheet.rewrite_with_format(0, 0, None)?;
+    unsafe {
+        assert!((*cell(&worksheet, 0, 0)).format.is_null());
+    }
+
+    // Writing again replaces the value and the format
+    worksheet.write_number(1, 0, 1.0, Some(&bold))?;
+    worksheet.write_number(1, 0, 2.0, None)?;
+    unsafe {
+        let cell = cell(&worksheet, 1, 0);
+        assert_eq!((*cell).u.number, 2.0);
+        assert!((*cell).format.is_null());
+    }
+
+    assert!(worksheet.rewrite_with_format(0, 1, Some(&money)).is_err());
+    assert!(worksheet.rewrite_with_format(5, 0, Some(&money)).is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_insert_chart_in_range() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_insert_chart_in_range.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        worksheet.write_number(i, 0, (i * 10).into(), None)?;
+    }
+    worksheet.set_column_pixels(2, 2, 100, None)?;
+    worksheet.set_row_pixels(3, 40, None)?;
+    let mut chart = workbook.add_chart(ChartType::Column);
+    chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    worksheet.insert_chart_in_range(1, 1, 19, 7, &chart)?;
+    unsafe {
+        let chart = (*(*worksheet.worksheet).chart_data).stqh_first;
+        assert_eq!(((*chart).row, (*chart).col), (1, 1));
+        // B:H is 6 default columns and one of 100 pixels, 2:20 is 18 default rows and one of 40 pixels
+        assert!(((*chart).x_scale - (6.0 * 64.0 + 100.0) / 480.0).abs() < 1e-9);
+        assert!(((*chart).y_scale - (18.0 * 20.0 + 40.0) / 288.0).abs() < 1e-9);
+    }
+    assert!(worksheet
+        .insert_chart_in_range(1, 1, 19, 7, &chart)
+        .is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_chart_set_size_pixels() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-chart-set_size_pixels.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    for i in 0..5 {
+        worksheet.write_number(i, 0, (i * 10).into(), None)?;
+    }
+    let mut chart = workbook.add_chart(ChartType::Column);
+    chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    chart.set_size_pixels(480, 320);
+    worksheet.insert_chart(1, 3, &chart)?;
+    let mut default_size = workbook.add_chart(ChartType::Column);
+    default_size.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    worksheet.insert_chart(20, 3, &default_size)?;
+    unsafe {
+        let chart = (*(*worksheet.worksheet).chart_data).stqh_first;
+        assert_eq!(((*chart).x_scale, (*chart).y_scale), (1.0, 320.0 / 288.0));
+        let default_size = (*chart).list_pointers.stqe_next;
+        assert_eq!(
+            ((*default_size).x_scale, (*default_size).y_scale),
+            (1.0, 1.0)
+        );
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_insert_image_fit_cell() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_insert_image_fit_cell.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let logo = include_bytes!("../../images/simple1.png");
+    worksheet.insert_image_fit_cell(0, 0, &logo[..], 200, 100)?;
+    unsafe {
+        let image = (*(*worksheet.worksheet).image_props).stqh_first;
+        // 425x165 pixels at 96 dpi, limited by the width
+        let expected = 200.0 * 95.9866 / 96.0 / 425.0;
+        assert!(((*image).x_scale - expected).abs() < 1e-4);
+        assert!(((*image).y_scale - expected).abs() < 1e-4);
+    }
+    assert!(worksheet
+        .insert_image_fit_cell(1, 0, b"not an image", 200, 100)
+        .is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_insert_image_description() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_insert_image_opt-description.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut options = ImageOptions {
+        description: Some("Company logo".to_string()),
+        ..ImageOptions::default()
+    };
+    worksheet.insert_image_opt(0, 0, "../images/simple1.png", &options)?;
+    options.description = Some("Logo from memory".to_string());
+    let logo = include_bytes!("../../images/simple1.png");
+    worksheet.insert_image_buffer_opt(10, 0, &logo[..], &options)?;
+    unsafe {
+        let image = (*(*worksheet.worksheet).image_props).stqh_first;
+        assert_eq!(
+            std::ffi::CStr::from_ptr((*image).description).to_str(),
+            Ok("Company logo")
+        );
+        let buffer_image = (*image).list_pointers.stqe_next;
+        assert_eq!(
+            std::ffi::CStr::from_ptr((*buffer_image).description).to_str(),
+            Ok("Logo from memory")
+        );
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_set_selection_a1() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_set_selection_a1.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    assert!(worksheet.set_selection_a1("A1:C3", "D4").is_err());
+    worksheet.set_selection_a1("A1:C3", "B2")?;
+    unsafe {
+        let selection = (*(*worksheet.worksheet).selections).stqh_first;
+        let text = |field: &[std::os::raw::c_char]| {
+            std::ffi::CStr::from_ptr(field.as_ptr())
+                .to_str()
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(text(&(*selection).active_cell), "B2");
+        assert_eq!(text(&(*selection).sqref), "A1:C3");
+        assert!((*selection).list_pointers.stqe_next.is_null());
+    }
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_write_banded_block() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_banded_block.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut even_format = Format::new();
+    even_format.set_bg_color(FormatColor::White);
+    let mut odd_format = Format::new();
+    odd_format.set_bg_color(FormatColor::Silver);
+    let rows: Vec<Vec<CellValue>> = vec![
+        vec!["East".into(), 1200.into()],
+        vec!["West".into(), 950.into()],
+        vec!["North".into(), 1010.into()],
+        vec!["South".into(), CellValue::Blank],
+    ];
+    worksheet.write_banded_block(1, 1, &rows, Some(&even_format), Some(&odd_format))?;
+    unsafe {
+        let cell_format = |row, col| {
+            let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, row);
+            (*libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, col)).format
+        };
+        let even = workbook.get_internal_format(&even_format)?;
+        let odd = workbook.get_internal_format(&odd_format)?;
+        assert_ne!(even, odd);
+        assert_eq!((cell_format(1, 1), cell_format(1, 2)), (even, even));
+        assert_eq!((cell_format(2, 1), cell_format(2, 2)), (odd, odd));
+        assert_eq!(cell_format(3, 1), even);
+        // The blank value is written because the row has a format
+        assert_eq!(cell_format(4, 2), odd);
+    }
+    assert!(worksheet
+        .write_banded_block(1_048_575, 0, &rows, None, None)
+        .is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_write_report_table() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_report_table.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut style = ReportStyle::new();
+    style.header_format.set_bold();
+    let mut body_format = Format::new();
+    body_format.set_num_format("#,##0");
+    let mut band_format = body_format.clone();
+    band_format.set_bg_color(FormatColor::Silver);
+    style.body_format = Some(body_format.clone());
+    style.band_format = Some(band_format.clone());
+
+    let rows: Vec<Vec<CellValue>> = vec![
+        vec!["East".into(), 1200.into()],
+        vec!["West".into(), 950.into(), "note".into()],
+        vec!["North".into(), 1010.into()],
+    ];
+    worksheet.write_report_table(2, 1, &["Region", "Sales"], &rows, &style)?;
+    assert_eq!(worksheet.used_range(), Some(CellRange::new(2, 1, 5, 3)));
+    unsafe {
+        let cell_format = |row, col| {
+            let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, row);
+            (*libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, col)).format
+        };
+        assert_eq!(
+            cell_format(2, 1),
+            workbook.get_internal_format(&style.header_format)?
+        );
+        assert_eq!(
+            cell_format(3, 2),
+            workbook.get_internal_format(&body_format)?
+        );
+        assert_eq!(
+            cell_format(4, 2),
+            workbook.get_internal_format(&band_format)?
+        );
+        assert_eq!(
+            cell_format(5, 2),
+            workbook.get_internal_format(&body_format)?
+        );
+
+        let autofilter = (*worksheet.worksheet).autofilter;
+        assert_eq!(
+            (
+                autofilter.first_row,
+                autofilter.first_col,
+                autofilter.last_row,
+                autofilter.last_col
+            ),
+            (2, 1, 5, 3)
+        );
+        let panes = (*worksheet.worksheet).panes;
+        assert_eq!((panes.first_row, panes.first_col), (3, 0));
+    }
+    assert!(worksheet
+        .write_report_table(10, 0, &[], &rows, &style)
+        .is_err());
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_write_table_data() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_table_data.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut bold = Format::new();
+    bold.set_bold();
+    let rows: Vec<Vec<CellValue>> = vec![
+        vec!["Apples".into(), 12.into(), true.into()],
+        vec!["Pears".into(), 7.5.into(), false.into()],
+        vec![
+            "Plums".into(),
+            CellValue::Blank,
+            CellValue::Formula("=B2+B3".to_string()),
+        ],
+    ];
+    worksheet.write_table_data(
+        1,
+        1,
+        &rows,
+        Some(&["Fruit", "Amount", "In stock"]),
+        Some(&bold),
+        None,
+    )?;
+    assert_eq!(worksheet.used_range(), Some(CellRange::new(1, 1, 4, 3)));
+    unsafe {
+        let header = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, 1);
+        let cell = libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(header, 2);
+        assert_eq!((*cell).format, workbook.get_internal_format(&bold)?);
+        let last = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, 4);
+        let blank = libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(last, 2);
+        assert!(blank.is_null());
+        let formula = libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(last, 3);
+        assert_eq!(
+            u32::from((*formula).type_),
+            libxlsxwriter_sys::cell_types_FORMULA_CELL
+        );
+    }
+
+    // Without a header the body starts at the first row
+    let mut worksheet = workbook.add_worksheet(None)?;
+    worksheet.write_table_data(0, 0, &rows, None, None, None)?;
+    assert_eq!(worksheet.used_range(), Some(CellRange::new(0, 0, 2, 2)));
+    workbook.close()?;
+    Ok(())
+}
+
+#[test]
+fn test_write_key_values() -> Result<(), XlsxError> {
+    let workbook = Workbook::new("test-worksheet_write_key_values-2.xlsx")?;
+    let mut worksheet = workbook.add_worksheet(None)?;
+    let mut key_format = Format::new();
+    key_format.set_bold();
+    let mut value_format = Format::new();
+    value_format
+        .set_num_format("yyyy-mm-dd")
+        .set_align(FormatAlignment::Left);
+
+    worksheet.write_key_values(
+        1,
+        1,
+        &[
+            ("Title", "Quarterly report".into()),
+            ("Rows", 1234.into()),
+            ("Ratio", 0.25.into()),
+            ("Approved", false.into()),
+            ("Created", DateTime::date(2022, 3, 31).into()),
+        ],
+        Some(&key_format),
+        Some(&value_format),
+    )?;
+    assert_eq!(worksheet.used_range(), Some(CellRange::new(1, 1, 5, 2)));
+    assert_eq!(CellValue::from(None::<f64>), CellValue::Blank);
+    workbook.close()?;
+    Ok(())
+}
@@ -0,0 +1,269 @@
+use super::worksheet::{CellRange, WorksheetCol, WorksheetRow};
+use super::XlsxError;
+
+/// Maximum number of rows in an Excel worksheet.
+const ROW_MAX: u64 = 1_048_576;
+/// Maximum number of columns in an Excel worksheet.
+const COL_MAX: u64 = 16_384;
+
+/// Convert a zero indexed column number to an Excel column name.
+/// ```rust
+/// # use xlsxwriter::utility::col_to_name;
+/// assert_eq!(col_to_name(0), "A");
+/// assert_eq!(col_to_name(26), "AA");
+/// ```
+#[must_use]
+pub fn col_to_name(col: WorksheetCol) -> String {
+    let mut name = Vec::new();
+    let mut col_num = u32::from(col) + 1;
+    while col_num > 0 {
+        let remainder = ((col_num - 1) % 26) as u8;
+        name.push(b'A' + remainder);
+        col_num = (col_num - 1) / 26;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap()
+}
+
+/// Convert a zero indexed row and column cell reference to an A1 style string.
+/// ```rust
+/// # use xlsxwriter::utility::rowcol_to_cell;
+/// assert_eq!(rowcol_to_cell(2, 1), "B3");
+/// ```
+#[must_use]
+pub fn rowcol_to_cell(row: WorksheetRow, col: WorksheetCol) -> String {
+    format!("{}{}", col_to_name(col), u64::from(row) + 1)
+}
+
+/// Convert zero indexed row and column cell references to an A1 style range string.
+///
+/// A range of a single cell is written as a cell reference, like Excel does.
+/// ```rust
+/// # use xlsxwriter::utility::rowcol_to_range;
+/// assert_eq!(rowcol_to_range(0, 0, 9, 2), "A1:C10");
+/// assert_eq!(rowcol_to_range(3, 3, 3, 3), "D4");
+/// ```
+#[must_use]
+pub fn rowcol_to_range(
+    first_row: WorksheetRow,
+    first_col: WorksheetCol,
+    last_row: WorksheetRow,
+    last_col: WorksheetCol,
+) -> String {
+    if first_row == last_row && first_col == last_col {
+        rowcol_to_cell(first_row, first_col)
+    } else {
+        format!(
+            "{}:{}",
+            rowcol_to_cell(first_row, first_col),
+            rowcol_to_cell(last_row, last_col)
+        )
+    }
+}
+
+/// Convert an A1 style cell reference to zero indexed row and column numbers.
+///
+/// Absolute references such as `$B$3` are accepted.
+/// ```rust
+/// # use xlsxwriter::utility::cell_to_rowcol;
+/// assert_eq!(cell_to_rowcol("B3").unwrap(), (2, 1));
+/// assert_eq!(cell_to_rowcol("$AA$10").unwrap(), (9, 26));
+/// assert!(cell_to_rowcol("3B").is_err());
+/// ```
+pub fn cell_to_rowcol(cell: &str) -> Result<(WorksheetRow, WorksheetCol), XlsxError> {
+    let reference = cell.trim();
+    let rest = reference.strip_prefix('$').unwrap_or(reference);
+    let col_len = rest.bytes().take_while(|x| x.is_ascii_alphabetic()).count();
+    let (col_str, rest) = rest.split_at(col_len);
+    let row_str = rest.strip_prefix('$').unwrap_or(rest);
+
+    if col_str.is_empty()
+        || col_str.len() > 3
+        || row_str.is_empty()
+        || !row_str.bytes().all(|x| x.is_ascii_digit())
+    {
+        return Err(XlsxError::invalid_cell_reference(cell));
+    }
+
+    let col = col_str.bytes().fold(0u64, |acc, x| {
+        acc * 26 + u64::from(x.to_ascii_uppercase() - b'A') + 1
+    });
+    let row: u64 = row_str
+        .parse()
+        .map_err(|_| XlsxError::invalid_cell_reference(cell))?;
+    if row == 0 || row > ROW_MAX || col > COL_MAX {
+        return Err(XlsxError::invalid_cell_reference(cell));
+    }
+
+    Ok(((row - 1) as WorksheetRow, (col - 1) as WorksheetCol))
+}
+
+/// Convert an A1 style range such as `"A1:C10"` to a [`CellRange`].
+///
+/// A single cell reference is treated as a range of one cell, and a leading sheet name such as `Sheet1!` is ignored.
+/// The corners may be given in any order.
+/// ```rust
+/// # use xlsxwriter::prelude::*;
+/// # use xlsxwriter::utility::range_to_rowcol;
+/// assert_eq!(range_to_rowcol("A1:C10").unwrap(), CellRange::new(0, 0, 9, 2));
+/// assert_eq!(range_to_rowcol("'Sales Data'!$B$2").unwrap(), CellRange::new(1, 1, 1, 1));
+/// ```
+pub fn range_to_rowcol(range: &str) -> Result<CellRange, XlsxError> {
+    let reference = match range.rfind('!') {
+        Some(pos) => &range[pos + 1..],
+        None => range,
+    };
+    let (first, last) = match reference.split_once(':') {
+        Some((first, last)) => (first, last),
+        None => (reference, reference),
+    };
+    let (first_row, first_col) =
+        cell_to_rowcol(first).map_err(|_| XlsxError::invalid_cell_reference(range))?;
+    let (last_row, last_col) =
+        cell_to_rowcol(last).map_err(|_| XlsxError::invalid_cell_reference(range))?;
+
+    Ok(CellRange::new(
+        first_row.min(last_row),
+        first_col.min(last_col),
+        first_row.max(last_row),
+        first_col.max(last_col),
+    ))
+}
+
+/// Convert a path to a local file into an `external:` URL for [`crate::Worksheet::write_url`].
+///
+/// Excel stores links to local files with Windows separators, so `/` is replaced with `\` and a leading `.\` is
+/// removed. Relative paths are resolved by Excel from the directory of the workbook. A location in the target file
+/// can be given after `#`, such as `report.xlsx#Sheet2!A1`.
+///
+/// An error is returned for an empty path, for a URL such as `https://...` and for an absolute POSIX path such as
+/// `/home/user/file.pdf`, which cannot be opened on Windows.
+/// ```rust
+/// # use xlsxwriter::utility::file_link_url;
+/// assert_eq!(file_link_url("../docs/file.pdf").unwrap(), "external:..\\docs\\file.pdf");
+/// assert_eq!(file_link_url("C:\\temp\\foo.xlsx").unwrap(), "external:C:\\temp\\foo.xlsx");
+/// ```
+pub fn file_link_url(path: &str) -> Result<String, XlsxError> {
+    let trimmed = path.trim();
+    if trimmed.is_empty() {
+        return Err(XlsxError::invalid_file_link(path, "the path is empty"));
+    }
+    if trimmed.contains("://")
+        || trimmed.starts_with("internal:")
+        || trimmed.starts_with("external:")
+    {
+        return Err(XlsxError::invalid_file_link(path, "not a file path"));
+    }
+    let (file, location) = match trimmed.split_once('#') {
+        Some((file, location)) => (file, Some(location)),
+        None => (trimmed, None),
+    };
+    if file.starts_with('/') && !file.starts_with("//") {
+        return Err(XlsxError::invalid_file_link(
+            path,
+            "absolute POSIX paths cannot be opened on Windows",
+        ));
+    }
+    let mut file = file.replace('/', "\\");
+    while let Some(rest) = file.strip_prefix(".\\") {
+        file = rest.to_string();
+    }
+    if file.is_empty() {
+        return Err(XlsxError::invalid_file_link(path, "the path is empty"));
+    }
+    Ok(match location {
+        Some(location) => format!("external:{}#{}", file, location),
+        None => format!("external:{}", file),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cell_to_rowcol() -> Result<(), XlsxError> {
+        assert_eq!(cell_to_rowcol("A1")?, (0, 0));
+        assert_eq!(cell_to_rowcol("z26")?, (25, 25));
+        assert_eq!(cell_to_rowcol("$AZ$100")?, (99, 51));
+        assert_eq!(cell_to_rowcol("XFD1048576")?, (1_048_575, 16383));
+        for bad in &[
+            "", "A", "1", "A0", "XFE1", "A1048577", "A1B", "AAAA1", "A-1",
+        ] {
+            assert!(cell_to_rowcol(bad).is_err(), "{} should be invalid", bad);
+        }
+        for col in [0, 25, 26, 701, 702, 16383] {
+            assert_eq!(cell_to_rowcol(&rowcol_to_cell(7, col))?, (7, col));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_range_to_rowcol() -> Result<(), XlsxError> {
+        assert_eq!(range_to_rowcol("A1:A100")?, CellRange::new(0, 0, 99, 0));
+        assert_eq!(range_to_rowcol("C3:A1")?, CellRange::new(0, 0, 2, 2));
+        assert_eq!(
+            range_to_rowcol("Sheet1!$B$2:$D$4")?,
+            CellRange::new(1, 1, 3, 3)
+        );
+        assert_eq!(range_to_rowcol("E5")?, CellRange::new(4, 4, 4, 4));
+        assert!(range_to_rowcol("A1:").is_err());
+        assert!(range_to_rowcol("A1:B2:C3").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_file_link_url() -> Result<(), XlsxError> {
+        assert_eq!(
+            file_link_url("..\\docs\\file.pdf")?,
+            "external:..\\docs\\file.pdf"
+        );
+        assert_eq!(
+            file_link_url("../docs/file.pdf")?,
+            "external:..\\docs\\file.pdf"
+        );
+        assert_eq!(
+            file_link_url("./data/report.xlsx")?,
+            "external:data\\report.xlsx"
+        );
+        assert_eq!(
+            file_link_url("report.xlsx#Sheet2!A1")?,
+            "external:report.xlsx#Sheet2!A1"
+        );
+        assert_eq!(
+            file_link_url("//server/share/file.xlsx")?,
+            "external:\\\\server\\share\\file.xlsx"
+        );
+        for bad in &[
+            "",
+            " ",
+            "./",
+            "/home/user/file.pdf",
+            "https://example.com",
+            "internal:A1",
+        ] {
+            assert!(file_link_url(bad).is_err(), "{:?} should be invalid", bad);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_col_to_name() {
+        assert_eq!(col_to_name(0), "A");
+        assert_eq!(col_to_name(25), "Z");
+        assert_eq!(col_to_name(26), "AA");
+        assert_eq!(col_to_name(51), "AZ");
+        assert_eq!(col_to_name(52), "BA");
+        assert_eq!(col_to_name(701), "ZZ");
+        assert_eq!(col_to_name(702), "AAA");
+        assert_eq!(col_to_name(16383), "XFD");
+    }
+
+    #[test]
+    fn test_rowcol_to_range() {
+        assert_eq!(rowcol_to_cell(0, 0), "A1");
+        assert_eq!(rowcol_to_cell(1_048_575, 16383), "XFD1048576");
+        assert_eq!(rowcol_to_range(1, 1, 5, 3), "B2:D6");
+        assert_eq!(rowcol_to_range(5, 3, 5, 3), "D6");
+    }
+}
@@ -1,12 +1,355 @@
+use crate::chart::CHART_DATA_SHEET_NAME;
+use crate::worksheet::{PageSetup, SheetTemplate, WorksheetCol, WorksheetState, COL_COUNT};
 use crate::CStringHelper;
 
-use super::{Chart, ChartType, Format, Worksheet, XlsxError};
-use std::cell::RefCell;
-use std::collections::HashMap;
+use super::{
+    convert_bool, Chart, ChartType, DateTime, Format, FormatRef, Worksheet, WorksheetNameError,
+    XlsxError,
+};
+use std::cell::{Cell, RefCell, RefMut};
+use std::collections::{HashMap, HashSet};
 use std::ffi::CString;
-use std::os::raw::c_char;
+use std::io::Write;
+use std::os::raw::{c_char, c_void};
+use std::path::Path;
 use std::pin::Pin;
 use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// Document properties of the workbook, shown in Excel under File -> Info -> Properties.
+///
+/// Every field is optional; fields left as `None` are not written.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct DocProperties {
+    pub title: Option<String>,
+    pub subject: Option<String>,
+    pub author: Option<String>,
+    pub manager: Option<String>,
+    pub company: Option<String>,
+    pub category: Option<String>,
+    pub keywords: Option<String>,
+    pub comments: Option<String>,
+    pub status: Option<String>,
+    /// Base URL used to resolve relative hyperlinks in the workbook.
+    pub hyperlink_base: Option<String>,
+}
+
+impl DocProperties {
+    pub fn new() -> DocProperties {
+        DocProperties::default()
+    }
+
+    /// Set the keywords from a list, joined with `"; "` as Excel does for the tags entered in the properties of a
+    /// workbook.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// let mut properties = DocProperties::new();
+    /// properties.keywords(&["sales", "2024", "quarterly"]);
+    /// assert_eq!(properties.keywords.as_deref(), Some("sales; 2024; quarterly"));
+    /// ```
+    pub fn keywords(&mut self, keywords: &[&str]) -> &mut Self {
+        self.keywords = Some(keywords.join("; "));
+        self
+    }
+}
+
+/// Event passed to the callback of [`Workbook::close_with_progress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CloseProgress {
+    /// Writing the file has started.
+    Started { worksheets: usize },
+    /// The file has been written.
+    Finished { elapsed: Duration },
+}
+
+/// Number of styles registered with a workbook, returned by [`Workbook::debug_style_info`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct StyleInfo {
+    /// Distinct formats used by cells, rows, columns and other objects.
+    pub formats: usize,
+    /// Distinct fonts of the formats.
+    pub fonts: usize,
+    /// Distinct fills of the formats.
+    pub fills: usize,
+    /// Distinct borders of the formats.
+    pub borders: usize,
+}
+
+/// Value of a custom document property, see [`Workbook::set_custom_property`].
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum CustomPropertyValue {
+    String(String),
+    Number(f64),
+    Integer(i32),
+    Boolean(bool),
+    DateTime(DateTime),
+}
+
+impl From<&str> for CustomPropertyValue {
+    fn from(val: &str) -> Self {
+        CustomPropertyValue::String(val.to_string())
+    }
+}
+
+impl From<String> for CustomPropertyValue {
+    fn from(val: String) -> Self {
+        CustomPropertyValue::String(val)
+    }
+}
+
+impl From<f64> for CustomPropertyValue {
+    fn from(val: f64) -> Self {
+        CustomPropertyValue::Number(val)
+    }
+}
+
+impl From<i32> for CustomPropertyValue {
+    fn from(val: i32) -> Self {
+        CustomPropertyValue::Integer(val)
+    }
+}
+
+impl From<bool> for CustomPropertyValue {
+    fn from(val: bool) -> Self {
+        CustomPropertyValue::Boolean(val)
+    }
+}
+
+impl From<DateTime> for CustomPropertyValue {
+    fn from(val: DateTime) -> Self {
+        CustomPropertyValue::DateTime(val)
+    }
+}
+
+/// Standard and custom document properties, applied together with [`Workbook::set_metadata`].
+/// ```rust
+/// # use xlsxwriter::prelude::*;
+/// let mut metadata = DocumentMetadata::new();
+/// metadata
+///     .set_title("Quarterly figures")
+///     .set_author("Finance team")
+///     .add_custom_property("Department", "Controlling")
+///     .add_custom_property("Revision", 3);
+/// assert_eq!(metadata.properties.title.as_deref(), Some("Quarterly figures"));
+/// ```
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+pub struct DocumentMetadata {
+    pub properties: DocProperties,
+    /// Custom properties in the order they are written, shown in Excel under File -> Info -> Properties -> Advanced.
+    pub custom_properties: Vec<(String, CustomPropertyValue)>,
+}
+
+impl DocumentMetadata {
+    pub fn new() -> DocumentMetadata {
+        DocumentMetadata::default()
+    }
+
+    pub fn set_title(&mut self, title: &str) -> &mut Self {
+        self.properties.title = Some(title.to_string());
+        self
+    }
+
+    pub fn set_subject(&mut self, subject: &str) -> &mut Self {
+        self.properties.subject = Some(subject.to_string());
+        self
+    }
+
+    pub fn set_author(&mut self, author: &str) -> &mut Self {
+        self.properties.author = Some(author.to_string());
+        self
+    }
+
+    pub fn set_manager(&mut self, manager: &str) -> &mut Self {
+        self.properties.manager = Some(manager.to_string());
+        self
+    }
+
+    pub fn set_company(&mut self, company: &str) -> &mut Self {
+        self.properties.company = Some(company.to_string());
+        self
+    }
+
+    pub fn set_category(&mut self, category: &str) -> &mut Self {
+        self.properties.category = Some(category.to_string());
+        self
+    }
+
+    pub fn set_keywords(&mut self, keywords: &str) -> &mut Self {
+        self.properties.keywords = Some(keywords.to_string());
+        self
+    }
+
+    pub fn set_comments(&mut self, comments: &str) -> &mut Self {
+        self.properties.comments = Some(comments.to_string());
+        self
+    }
+
+    pub fn set_status(&mut self, status: &str) -> &mut Self {
+        self.properties.status = Some(status.to_string());
+        self
+    }
+
+    pub fn set_hyperlink_base(&mut self, hyperlink_base: &str) -> &mut Self {
+        self.properties.hyperlink_base = Some(hyperlink_base.to_string());
+        self
+    }
+
+    pub fn add_custom_property<T: Into<CustomPropertyValue>>(
+        &mut self,
+        name: &str,
+        value: T,
+    ) -> &mut Self {
+        self.custom_properties
+            .push((name.to_string(), value.into()));
+        self
+    }
+}
+
+/// Options of [`Workbook::new_with_options`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct WorkbookOptions {
+    /// Reduce the amount of data stored in memory so that large files can be written efficiently. Off by default;
+    /// see [`Workbook::new_with_options`] for the limitations of this mode.
+    pub constant_memory: bool,
+    /// Directory of the temporary files that libxlsxwriter stores workbook data in before assembling the XLSX file.
+    /// The system's temp directory is used by default; set this if it isn't accessible or doesn't have enough space.
+    pub tmpdir: Option<String>,
+    /// Use ZIP64 extensions when writing very large xlsx files, so that the zip container or the XML files in it can
+    /// be greater than 4 GB. Off by default.
+    pub use_zip64: bool,
+}
+
+impl WorkbookOptions {
+    pub fn new() -> WorkbookOptions {
+        WorkbookOptions::default()
+    }
+}
+
+/// Builder of a [`Workbook`] with all workbook level options, written as a file with [`WorkbookBuilder::build`] or
+/// to memory with [`WorkbookBuilder::build_buffer`].
+/// ```rust
+/// # use xlsxwriter::prelude::*;
+/// # fn main() -> Result<(), XlsxError> {
+/// let mut properties = DocProperties::new();
+/// properties.title = Some("Quarterly report".to_string());
+/// let workbook = WorkbookBuilder::new()
+///     .constant_memory(true)
+///     .tmpdir("target")
+///     .properties(properties)
+///     .build("test-workbook_builder.xlsx")?;
+/// let mut worksheet = workbook.add_worksheet(None)?;
+/// worksheet.write_string(0, 0, "Hello Excel", None)?;
+/// workbook.close()
+/// # }
+/// ```
+/// There is no calculation mode option, as libxlsxwriter always sets Excel's "full calculation on load" flag; see
+/// [Formula recalculation](Workbook#formula-recalculation).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct WorkbookBuilder {
+    options: WorkbookOptions,
+    properties: Option<DocProperties>,
+}
+
+impl WorkbookBuilder {
+    pub fn new() -> WorkbookBuilder {
+        WorkbookBuilder::default()
+    }
+
+    /// See [`WorkbookOptions::constant_memory`].
+    pub fn constant_memory(&mut self, constant_memory: bool) -> &mut Self {
+        self.options.constant_memory = constant_memory;
+        self
+    }
+
+    /// See [`WorkbookOptions::tmpdir`].
+    pub fn tmpdir(&mut self, tmpdir: &str) -> &mut Self {
+        self.options.tmpdir = Some(tmpdir.to_string());
+        self
+    }
+
+    /// See [`WorkbookOptions::use_zip64`].
+    pub fn use_zip64(&mut self, use_zip64: bool) -> &mut Self {
+        self.options.use_zip64 = use_zip64;
+        self
+    }
+
+    /// Document properties set with [`Workbook::set_properties`] when the workbook is built.
+    pub fn properties(&mut self, properties: DocProperties) -> &mut Self {
+        self.properties = Some(properties);
+        self
+    }
+
+    /// Create a workbook that is written to `filename` when it is closed, like [`Workbook::new_with_options`].
+    pub fn build(&self, filename: &str) -> Result<Workbook, XlsxError> {
+        let workbook = Workbook::new_with_options(filename, &self.options)?;
+        self.apply(workbook)
+    }
+
+    /// Create a workbook that is written to memory. The file is returned by [`Workbook::close_buffer`].
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let workbook = WorkbookBuilder::new().build_buffer()?;
+    /// let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_string(0, 0, "Hello Excel", None)?;
+    /// let bytes = workbook.close_buffer()?;
+    /// assert!(bytes.starts_with(b"PK"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build_buffer(&self) -> Result<Workbook, XlsxError> {
+        let workbook = Workbook::create(None, &self.options, Some(Box::default()))?;
+        self.apply(workbook)
+    }
+
+    fn apply(&self, workbook: Workbook) -> Result<Workbook, XlsxError> {
+        if let Some(properties) = &self.properties {
+            workbook.set_properties(properties)?;
+        }
+        Ok(workbook)
+    }
+}
+
+extern "C" {
+    fn free(ptr: *mut c_void);
+}
+
+/// Output buffer that libxlsxwriter writes the file to when the workbook is closed. The buffer is allocated by
+/// libxlsxwriter and freed with the C allocator.
+#[derive(Debug)]
+struct OutputBuffer {
+    data: *const c_char,
+    size: usize,
+}
+
+impl Default for OutputBuffer {
+    fn default() -> Self {
+        OutputBuffer {
+            data: std::ptr::null(),
+            size: 0,
+        }
+    }
+}
+
+impl OutputBuffer {
+    fn as_bytes(&self) -> &[u8] {
+        if self.data.is_null() {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(self.data as *const u8, self.size) }
+        }
+    }
+}
+
+impl Drop for OutputBuffer {
+    fn drop(&mut self) {
+        if !self.data.is_null() {
+            unsafe {
+                free(self.data as *mut c_void);
+            }
+        }
+    }
+}
 
 /// The Workbook is the main object exposed by the libxlsxwriter library. It represents the entire spreadsheet as you see it in Excel and internally it represents the Excel file as it is written on disk.
 ///
@@ -19,10 +362,25 @@ use std::rc::Rc;
 ///     workbook.close()
 /// }
 /// ```
+///
+/// ### Formula recalculation
+/// libxlsxwriter doesn't calculate formula results. Instead every workbook it writes has Excel's "full calculation on load"
+/// flag set, so Excel recalculates all formulas when the file is opened and there is no option to turn on.
+/// Applications that can't calculate formulas only show the stored results; use [`Worksheet::write_formula_num`] or
+/// [`Worksheet::write_formula_str`] to store a result for those.
 pub struct Workbook {
     workbook: *mut libxlsxwriter_sys::lxw_workbook,
     pub(crate) const_str: Rc<RefCell<Vec<Pin<Box<CString>>>>>,
     format_map: Rc<RefCell<HashMap<Format, *mut libxlsxwriter_sys::lxw_format>>>,
+    interned_formats: Rc<RefCell<HashSet<Rc<Format>>>>,
+    worksheet_state: Rc<RefCell<HashMap<*mut libxlsxwriter_sys::lxw_worksheet, WorksheetState>>>,
+    string_url_conversion: Rc<Cell<bool>>,
+    default_page_setup: Rc<RefCell<Option<PageSetup>>>,
+    default_font: Rc<RefCell<Option<Format>>>,
+    chart_data: Rc<Cell<Option<(*mut libxlsxwriter_sys::lxw_worksheet, WorksheetCol)>>>,
+    /// Set for workbooks created with [`WorkbookBuilder::build_buffer`]. libxlsxwriter keeps pointers to it, so it
+    /// is boxed and must live until the workbook is closed.
+    output_buffer: Option<Box<OutputBuffer>>,
 }
 
 impl Workbook {
@@ -34,9 +392,13 @@ impl Workbook {
         if let Some(p) = map.get(format) {
             Ok(*p)
         } else {
+            format.validate()?;
             unsafe {
                 let new_format = libxlsxwriter_sys::workbook_add_format(self.workbook);
                 format.set_internal_format(new_format)?;
+                if let Some(default_font) = self.default_font.borrow().as_ref() {
+                    format.set_internal_default_font(new_format, default_font)?;
+                }
                 map.insert(format.clone(), new_format);
                 Ok(new_format)
             }
@@ -54,6 +416,40 @@ impl Workbook {
         }
     }
 
+    pub(crate) fn worksheet_state(
+        &self,
+        worksheet: *mut libxlsxwriter_sys::lxw_worksheet,
+    ) -> RefMut<'_, WorksheetState> {
+        RefMut::map(self.worksheet_state.borrow_mut(), |x| {
+            x.entry(worksheet).or_default()
+        })
+    }
+
+    /// Hidden worksheet holding the values of [`Chart::add_series_from_slice`], and its next free column.
+    pub(crate) fn chart_data_column(&self) -> Result<(Worksheet<'_>, WorksheetCol), XlsxError> {
+        let (worksheet, col) = match self.chart_data.get() {
+            Some((worksheet, col)) => (
+                Worksheet {
+                    _workbook: self,
+                    worksheet,
+                },
+                col,
+            ),
+            None => {
+                let mut worksheet = self.add_worksheet(Some(CHART_DATA_SHEET_NAME))?;
+                worksheet.hide();
+                (worksheet, 0)
+            }
+        };
+        if u32::from(col) >= COL_COUNT {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_WORKSHEET_INDEX_OUT_OF_RANGE,
+            ));
+        }
+        self.chart_data.set(Some((worksheet.worksheet, col + 1)));
+        Ok((worksheet, col))
+    }
+
     pub(crate) fn register_str(&self, s: &str) -> Result<*const c_char, XlsxError> {
         let c = Box::pin(CString::new(s)?);
         let p = c.as_ptr();
@@ -72,9 +468,33 @@ impl Workbook {
         }
     }
 
+    /// Check that the directory of the output file exists, so that a wrong path is reported when the workbook is
+    /// created instead of when it is closed.
+    fn check_output_path(filename: &str) -> Result<(), XlsxError> {
+        let directory = match Path::new(filename).parent() {
+            Some(directory) if !directory.as_os_str().is_empty() => directory,
+            _ => return Ok(()),
+        };
+        match std::fs::metadata(directory) {
+            Ok(metadata) if !metadata.is_dir() => Err(XlsxError::invalid_output_path(
+                filename,
+                format!("{} is not a directory", directory.display()),
+            )),
+            Ok(_) => Ok(()),
+            Err(e) => Err(XlsxError::invalid_output_path(
+                filename,
+                format!("cannot access directory {}: {}", directory.display(), e),
+            )),
+        }
+    }
+
     /// This function is used to create a new Excel workbook with a given filename.
     /// When specifying a filename it is recommended that you use an .xlsx extension or Excel will generate a warning when opening the file.
+    ///
+    /// An error is returned if the directory of the file doesn't exist. Whether the file can be written is only known
+    /// when the workbook is closed.
     pub fn new(filename: &str) -> Result<Workbook, XlsxError> {
+        Self::check_output_path(filename)?;
         unsafe {
             let workbook_name = Box::pin(CString::new(filename)?);
             let raw_workbook = libxlsxwriter_sys::workbook_new(workbook_name.as_ptr());
@@ -85,6 +505,13 @@ impl Workbook {
                 workbook: raw_workbook,
                 const_str: Rc::new(RefCell::new(vec![workbook_name])),
                 format_map: Rc::new(RefCell::new(HashMap::new())),
+                interned_formats: Rc::new(RefCell::new(HashSet::new())),
+                worksheet_state: Rc::new(RefCell::new(HashMap::new())),
+                string_url_conversion: Rc::new(Cell::new(false)),
+                default_page_setup: Rc::new(RefCell::new(None)),
+                default_font: Rc::new(RefCell::new(None)),
+                chart_data: Rc::new(Cell::new(None)),
+                output_buffer: None,
             })
         }
     }
@@ -93,27 +520,25 @@ impl Workbook {
     /// ```rust
     /// # use xlsxwriter::prelude::*;
     /// # fn main() -> Result<(), XlsxError> {
-    /// let workbook = Workbook::new_with_options("test-workbook_with_options.xlsx", true, Some("target"), true)?;
+    /// let options = WorkbookOptions {
+    ///     constant_memory: true,
+    ///     tmpdir: Some("target".to_string()),
+    ///     use_zip64: true,
+    /// };
+    /// let workbook = Workbook::new_with_options("test-workbook_with_options.xlsx", &options)?;
     /// let mut worksheet = workbook.add_worksheet(None)?;
     /// worksheet.write_string(0, 0, "Hello Excel", None)?;
     /// workbook.close()
     /// # }
     /// ```
     ///
-    /// The options that can be set are:
-    /// * `constant_memory`: This option reduces the amount of data stored in memory so that large files can be written efficiently.
-    ///   This option is off by default. See the note below for limitations when this mode is on.
-    /// * `tmpdir`: libxlsxwriter stores workbook data in temporary files prior to assembling the final XLSX file. The temporary
-    ///   files are created in the system's temp directory. If the default temporary directory isn't accessible to your application,
-    ///   or doesn't contain enough space, you can specify an alternative location using the tmpdir option.
-    /// * `use_zip64`: Make the zip library use ZIP64 extensions when writing very large xlsx files to allow the zip container, or
-    ///   individual XML files within it, to be greater than 4 GB. See ZIP64 on Wikipedia for more information. This option is
-    ///   off by default.
+    /// See [`WorkbookOptions`] for the options that can be set.
     ///
     /// ### Note
     /// In `constant_memory` mode each row of in-memory data is written to disk and then freed when a new row is started via one
     /// of the `Worksheet::write_*()` functions. Therefore, once this option is active data should be written in sequential row
-    /// by row order. For this reason [`Worksheet::merge_range()`] and some other row based functionality doesn't work in this mode.
+    /// by row order: data written to a row after the next row has been started is lost. For this reason
+    /// [`Worksheet::merge_range()`] and some other row based functionality doesn't work in this mode.
     /// See [Constant Memory Mode](https://libxlsxwriter.github.io/working_with_memory.html#ww_mem_constant) for more details.
     ///
     /// Also, in `constant_memory` mode the library uses temp file storage for worksheet data. This can lead to an issue on OSes
@@ -123,49 +548,147 @@ impl Workbook {
     /// for more details.
     pub fn new_with_options(
         filename: &str,
-        constant_memory: bool,
-        tmpdir: Option<&str>,
-        use_zip64: bool,
+        options: &WorkbookOptions,
     ) -> Result<Workbook, XlsxError> {
-        let mut c_string_helper = CStringHelper::new();
-        let tmpdir_ptr = c_string_helper.add_opt(tmpdir)?;
+        Self::check_output_path(filename)?;
+        Self::create(Some(filename), options, None)
+    }
+
+    /// Create a workbook that is written to memory, like [`WorkbookBuilder::build_buffer`] with the default options.
+    /// The file is returned by [`Workbook::close_buffer`].
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let workbook = Workbook::new_buffer()?;
+    /// let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_string(0, 0, "Hello Excel", None)?;
+    /// let bytes = workbook.close_buffer()?;
+    /// assert!(bytes.starts_with(b"PK"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// libxlsxwriter writes the XML files of the workbook to temporary files before they are zipped, even when the
+    /// workbook is written to memory. Enable the `use-fmemopen` feature to keep them in memory, for targets without a
+    /// writable temp directory such as WASM or serverless functions. `constant_memory` mode always needs temporary
+    /// files, so it isn't available there.
+    pub fn new_buffer() -> Result<Workbook, XlsxError> {
+        Self::create(None, &WorkbookOptions::default(), Some(Box::default()))
+    }
+
+    /// Create a workbook that is written to `filename`, or to `output_buffer` if there is no file name.
+    fn create(
+        filename: Option<&str>,
+        options: &WorkbookOptions,
+        mut output_buffer: Option<Box<OutputBuffer>>,
+    ) -> Result<Workbook, XlsxError> {
+        let workbook_name = filename
+            .map(|x| CString::new(x).map(Box::pin))
+            .transpose()?;
+        // The temporary directory is used until the workbook is closed, so it is kept with the workbook strings
+        let tmpdir = options
+            .tmpdir
+            .as_deref()
+            .map(|x| CString::new(x).map(Box::pin))
+            .transpose()?;
 
         unsafe {
             let mut workbook_options = libxlsxwriter_sys::lxw_workbook_options {
-                constant_memory: u8::from(constant_memory),
-                tmpdir: tmpdir_ptr as *mut c_char,
-                use_zip64: u8::from(use_zip64),
-                output_buffer: std::ptr::null_mut(),
-                output_buffer_size: std::ptr::null_mut(),
+                constant_memory: convert_bool(options.constant_memory),
+                tmpdir: tmpdir
+                    .as_ref()
+                    .map_or_else(std::ptr::null_mut, |x| x.as_ptr() as *mut c_char),
+                use_zip64: convert_bool(options.use_zip64),
+                output_buffer: output_buffer.as_mut().map_or_else(std::ptr::null_mut, |x| {
+                    &mut x.data as *mut *const c_char as _
+                }),
+                output_buffer_size: output_buffer
+                    .as_mut()
+                    .map_or_else(std::ptr::null_mut, |x| &mut x.size as *mut usize as _),
             };
 
-            let workbook_name = Box::pin(CString::new(filename).expect("Null Error"));
-
-            let raw_workbook =
-                libxlsxwriter_sys::workbook_new_opt(workbook_name.as_ptr(), &mut workbook_options);
+            let raw_workbook = libxlsxwriter_sys::workbook_new_opt(
+                workbook_name
+                    .as_ref()
+                    .map_or_else(std::ptr::null, |x| x.as_ptr()),
+                &mut workbook_options,
+            );
             if raw_workbook.is_null() {
                 unreachable!()
             }
+            let mut const_str: Vec<_> = workbook_name.into_iter().collect();
+            const_str.extend(tmpdir);
             Ok(Workbook {
                 workbook: raw_workbook,
-                const_str: Rc::new(RefCell::new(vec![workbook_name])),
+                const_str: Rc::new(RefCell::new(const_str)),
                 format_map: Rc::new(RefCell::new(HashMap::new())),
+                interned_formats: Rc::new(RefCell::new(HashSet::new())),
+                worksheet_state: Rc::new(RefCell::new(HashMap::new())),
+                string_url_conversion: Rc::new(Cell::new(false)),
+                default_page_setup: Rc::new(RefCell::new(None)),
+                default_font: Rc::new(RefCell::new(None)),
+                chart_data: Rc::new(Cell::new(None)),
+                output_buffer,
             })
         }
     }
 
+    /// Check the rules of Excel for worksheet names, except that the name must be unique.
+    fn check_worksheet_name(name: &str) -> Result<(), XlsxError> {
+        let reason = if name.is_empty() {
+            Some(WorksheetNameError::Empty)
+        } else if name.chars().count() > 31 {
+            Some(WorksheetNameError::TooLong)
+        } else if let Some(c) = name.chars().find(|c| "[]:*?/\\".contains(*c)) {
+            Some(WorksheetNameError::InvalidCharacter(c))
+        } else if name.starts_with('\'') || name.ends_with('\'') {
+            Some(WorksheetNameError::Apostrophe)
+        } else if name.eq_ignore_ascii_case("History") {
+            Some(WorksheetNameError::Reserved)
+        } else {
+            None
+        };
+        match reason {
+            Some(reason) => Err(XlsxError::invalid_worksheet_name(name, reason)),
+            None => Ok(()),
+        }
+    }
+
+    /// The [`Workbook::add_worksheet`] function adds a new worksheet to a workbook.
+    ///
+    /// If `sheet_name` is `None` the default Excel convention will be followed, i.e. Sheet1, Sheet2, etc.
+    /// Sheet names are passed to libxlsxwriter as UTF-8, so non-ASCII names are supported. The name must be
+    /// no longer than 31 characters (not bytes), must not contain any of `[ ] : * ? / \` and must be unique
+    /// within the workbook. An invalid name is reported with [`XlsxError::worksheet_name_error`].
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-workbook-add_worksheet.xlsx")?;
+    /// let mut worksheet1 = workbook.add_worksheet(None)?; // Sheet1
+    /// let mut worksheet2 = workbook.add_worksheet(Some("Foglio2"))?;
+    /// let mut worksheet3 = workbook.add_worksheet(Some("売上"))?;
+    /// # workbook.close()
+    /// # }
+    /// ```
     pub fn add_worksheet<'a>(
         &'a self,
         sheet_name: Option<&str>,
     ) -> Result<Worksheet<'a>, XlsxError> {
-        let name_cstr =
-            sheet_name.map(|x| Box::pin(CString::new(x).expect("Cannot create CString")));
+        let name_cstr = sheet_name
+            .map(|x| CString::new(x).map(Box::pin))
+            .transpose()?;
         unsafe {
-            if let Some(sheet_name) = name_cstr.as_ref() {
+            if let (Some(name), Some(sheet_name)) = (sheet_name, name_cstr.as_ref()) {
+                Self::check_worksheet_name(name)?;
                 let result = libxlsxwriter_sys::workbook_validate_sheet_name(
                     self.workbook,
                     sheet_name.as_ptr(),
                 );
+                if result == libxlsxwriter_sys::lxw_error_LXW_ERROR_SHEETNAME_ALREADY_USED {
+                    return Err(XlsxError::invalid_worksheet_name(
+                        name,
+                        WorksheetNameError::AlreadyUsed,
+                    ));
+                }
                 if result != libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
                     return Err(XlsxError::new(result));
                 }
@@ -186,11 +709,106 @@ impl Workbook {
                 return Err(XlsxError::unknown_error());
             }
 
-            Ok(Worksheet {
+            let mut worksheet = Worksheet {
                 _workbook: self,
                 worksheet,
-            })
+            };
+            if let Some(page_setup) = self.default_page_setup.borrow().as_ref() {
+                worksheet.set_page_setup(page_setup)?;
+            }
+            Ok(worksheet)
+        }
+    }
+
+    /// Add a worksheet with [`Workbook::add_worksheet`] and run `setup` on it, so that the same setup can be shared
+    /// by the sheets of a report:
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-workbook-add_worksheet_with-1.xlsx")?;
+    /// let setup = |worksheet: &mut Worksheet| {
+    ///     worksheet.write_row(0, 0, &["Date".into(), "Amount".into()], Some(Format::new().set_bold()))?;
+    ///     worksheet.freeze_panes(1, 0);
+    ///     worksheet.autofilter(0, 0, 0, 1)
+    /// };
+    /// let mut january = workbook.add_worksheet_with(Some("January"), setup)?;
+    /// let mut february = workbook.add_worksheet_with(Some("February"), setup)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// If `setup` returns an error, the error is returned and the worksheet stays in the workbook as `setup` left it.
+    pub fn add_worksheet_with<'a, F>(
+        &'a self,
+        sheet_name: Option<&str>,
+        setup: F,
+    ) -> Result<Worksheet<'a>, XlsxError>
+    where
+        F: FnOnce(&mut Worksheet<'a>) -> Result<(), XlsxError>,
+    {
+        let mut worksheet = self.add_worksheet(sheet_name)?;
+        setup(&mut worksheet)?;
+        Ok(worksheet)
+    }
+
+    /// Add a worksheet with [`Workbook::add_worksheet`] and apply a [`SheetTemplate`] to it, see
+    /// [`Worksheet::apply_template`].
+    pub fn add_worksheet_from_template<'a>(
+        &'a self,
+        sheet_name: Option<&str>,
+        template: &SheetTemplate,
+    ) -> Result<Worksheet<'a>, XlsxError> {
+        self.add_worksheet_with(sheet_name, |worksheet| worksheet.apply_template(template))
+    }
+
+    /// Set the page setup applied to every worksheet added after this call, so that the print settings don't
+    /// have to be repeated for each sheet of a report. Worksheets added before are not changed.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let workbook = Workbook::new("test-workbook-set_default_page_setup.xlsx")?;
+    /// workbook.set_default_page_setup(&PageSetup {
+    ///     landscape: true,
+    ///     footer: Some("&CPage &P of &N".to_string()),
+    ///     ..PageSetup::new()
+    /// });
+    /// let mut january = workbook.add_worksheet(Some("January"))?; // landscape
+    /// let mut february = workbook.add_worksheet(Some("February"))?; // landscape
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn set_default_page_setup(&self, page_setup: &PageSetup) {
+        *self.default_page_setup.borrow_mut() = Some(page_setup.clone());
+    }
+
+    /// Set the font of the Normal style, which is used by every cell without a format of its own. Only the font name
+    /// and size of `format` are used.
+    ///
+    /// Formats that don't set a font name or size, such as a format that only sets bold, use the default font too,
+    /// whether they are used before or after this call.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let workbook = Workbook::new("test-workbook-set_default_format.xlsx")?;
+    /// workbook.set_default_format(Format::new().set_font_name("Arial").set_font_size(10.))?;
+    /// let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_string(0, 0, "Arial 10", None)?;
+    /// worksheet.write_string(1, 0, "Arial 10 bold", Some(Format::new().set_bold()))?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    ///
+    /// Excel calculates column widths from the default font, while libxlsxwriter positions images and charts assuming
+    /// the Calibri 11 font, so objects may be placed slightly differently with another default font.
+    pub fn set_default_format(&self, format: &Format) -> Result<(), XlsxError> {
+        format.validate()?;
+        unsafe {
+            Format::new().set_internal_default_font((*self.workbook).default_format, format)?;
         }
+        for (existing, internal) in self.format_map.borrow().iter() {
+            existing.set_internal_default_font(*internal, format)?;
+        }
+        *self.default_font.borrow_mut() = Some(format.clone());
+        Ok(())
     }
 
     /// This function returns a [`Worksheet`] object reference based on its name.
@@ -214,6 +832,46 @@ impl Workbook {
         }
     }
 
+    /// Open the workbook on `worksheet`, as with [`Worksheet::activate`]. An error is returned if `worksheet` belongs to
+    /// another workbook.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-workbook-set_active_worksheet-1.xlsx")?;
+    /// let mut data = workbook.add_worksheet(Some("Data"))?;
+    /// let mut summary = workbook.add_worksheet(Some("Summary"))?;
+    /// workbook.set_active_worksheet(&summary)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn set_active_worksheet(&self, worksheet: &Worksheet) -> Result<(), XlsxError> {
+        self.check_owned(worksheet)?;
+        unsafe {
+            libxlsxwriter_sys::worksheet_activate(worksheet.worksheet);
+        }
+        Ok(())
+    }
+
+    /// Scroll the worksheet tabs so that `worksheet` is the leftmost visible tab, as with
+    /// [`Worksheet::set_first_sheet`]. An error is returned if `worksheet` belongs to another workbook.
+    pub fn set_first_visible_worksheet(&self, worksheet: &Worksheet) -> Result<(), XlsxError> {
+        self.check_owned(worksheet)?;
+        unsafe {
+            libxlsxwriter_sys::worksheet_set_first_sheet(worksheet.worksheet);
+        }
+        Ok(())
+    }
+
+    fn check_owned(&self, worksheet: &Worksheet) -> Result<(), XlsxError> {
+        if std::ptr::eq(worksheet._workbook, self) {
+            Ok(())
+        } else {
+            Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ))
+        }
+    }
+
     /// Create new format struct.
     ///
     /// This function available only for compatibility. Please use [`Format::new`] to create new Format object.
@@ -223,6 +881,71 @@ impl Workbook {
         Format::new()
     }
 
+    /// Register a format with the workbook and return a cheap handle to it. Interning an equal format again returns
+    /// a handle to the same format, and the workbook adds a single style for it.
+    ///
+    /// This is useful when cells are written in a loop: the format can be built once and cloned without copying
+    /// its settings.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-workbook-intern_format.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let mut bold = Format::new();
+    /// bold.set_bold();
+    /// let bold = workbook.intern_format(bold)?;
+    /// for row in 0..100 {
+    ///     worksheet.write_number(row, 0, row.into(), Some(&bold))?;
+    /// }
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn intern_format(&self, format: Format) -> Result<FormatRef, XlsxError> {
+        if let Some(interned) = self.interned_formats.borrow().get(&format) {
+            return Ok(FormatRef {
+                format: interned.clone(),
+            });
+        }
+        self.get_internal_format(&format)?;
+        let interned = Rc::new(format);
+        self.interned_formats.borrow_mut().insert(interned.clone());
+        Ok(FormatRef { format: interned })
+    }
+
+    /// Count the formats registered with the workbook so far, and the distinct fonts, fills and borders they use.
+    /// This is meant for tests that check that generating a workbook doesn't add a new style for every cell:
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-workbook-debug_style_info-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// for row in 0..100 {
+    ///     worksheet.write_number(row, 0, row.into(), Some(Format::new().set_bold()))?;
+    /// }
+    /// assert_eq!(workbook.debug_style_info().formats, 1);
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// The counts are calculated from the settings of the formats, and don't include the default format of the
+    /// workbook. Formats that don't set a font, fill or border use the default one, which is not counted either.
+    pub fn debug_style_info(&self) -> StyleInfo {
+        let format_map = self.format_map.borrow();
+        let distinct = |part: fn(&Format) -> Format| {
+            format_map
+                .keys()
+                .map(part)
+                .filter(|x| *x != Format::default())
+                .collect::<HashSet<_>>()
+                .len()
+        };
+        StyleInfo {
+            formats: format_map.len(),
+            fonts: distinct(Format::font_part),
+            fills: distinct(Format::fill_part),
+            borders: distinct(Format::border_part),
+        }
+    }
+
     /// [`Workbook::add_chart`] function creates a new chart object that can be added to a worksheet.
     /// Available chart types are defined in [`ChartType`].
     #[must_use]
@@ -236,6 +959,7 @@ impl Workbook {
             Chart {
                 _workbook: self,
                 chart,
+                size: None,
             }
         }
     }
@@ -253,6 +977,8 @@ impl Workbook {
     /// # workbook.close()
     /// # }
     /// ```
+    ///
+    /// A name that refers to a range can also be the target of an internal hyperlink, see [`Worksheet::write_url`].
     pub fn define_name(&self, name: &str, formula: &str) -> Result<(), XlsxError> {
         unsafe {
             let result = libxlsxwriter_sys::workbook_define_name(
@@ -272,17 +998,285 @@ impl Workbook {
         }
     }
 
+    /// Turn on or off writing strings that look like URLs as hyperlinks in [`Worksheet::write_string`] for every
+    /// worksheet of the workbook. It is off by default, and [`Worksheet::set_autoconvert_urls`] overrides it for a
+    /// single worksheet.
+    ///
+    /// Excel allows at most 65530 hyperlinks per worksheet, so keep this off when exporting many URL-like strings:
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-workbook-set_string_url_conversion-1.xlsx")?;
+    /// workbook.set_string_url_conversion(true);
+    /// let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_string(0, 0, "https://github.com", None)?; // hyperlink
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn set_string_url_conversion(&self, enable: bool) {
+        self.string_url_conversion.set(enable);
+    }
+
+    pub(crate) fn string_url_conversion(&self) -> bool {
+        self.string_url_conversion.get()
+    }
+
+    /// This function is used to set the document properties such as Title, Author etc. for an Excel file created using libxlsxwriter.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let workbook = Workbook::new("test-workbook-set_properties.xlsx")?;
+    /// let mut worksheet = workbook.add_worksheet(None)?;
+    /// let mut properties = DocProperties::new();
+    /// properties.title = Some("This is an example spreadsheet".to_string());
+    /// properties.author = Some("John McNamara".to_string());
+    /// properties.hyperlink_base = Some("https://intranet.example.com/reports/".to_string());
+    /// workbook.set_properties(&properties)?;
+    /// worksheet.write_url(0, 0, "external:2024/summary.xlsx", None)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    ///
+    /// `hyperlink_base` is the base URL Excel uses to resolve relative hyperlinks in the workbook.
+    pub fn set_properties(&self, properties: &DocProperties) -> Result<(), XlsxError> {
+        let mut c_string_helper = CStringHelper::new();
+        unsafe {
+            let mut doc_properties = libxlsxwriter_sys::lxw_doc_properties {
+                title: c_string_helper.add_opt(properties.title.as_deref())? as _,
+                subject: c_string_helper.add_opt(properties.subject.as_deref())? as _,
+                author: c_string_helper.add_opt(properties.author.as_deref())? as _,
+                manager: c_string_helper.add_opt(properties.manager.as_deref())? as _,
+                company: c_string_helper.add_opt(properties.company.as_deref())? as _,
+                category: c_string_helper.add_opt(properties.category.as_deref())? as _,
+                keywords: c_string_helper.add_opt(properties.keywords.as_deref())? as _,
+                comments: c_string_helper.add_opt(properties.comments.as_deref())? as _,
+                status: c_string_helper.add_opt(properties.status.as_deref())? as _,
+                hyperlink_base: c_string_helper.add_opt(properties.hyperlink_base.as_deref())? as _,
+                created: 0,
+            };
+            let result =
+                libxlsxwriter_sys::workbook_set_properties(self.workbook, &mut doc_properties);
+            if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                Ok(())
+            } else {
+                Err(XlsxError::new(result))
+            }
+        }
+    }
+
+    /// Pass the worksheet settings that depend on the final contents of the worksheets to libxlsxwriter:
+    /// - the ranges collected by [`Worksheet::ignore_errors`], as libxlsxwriter keeps only the last range set for
+    ///   each warning.
+    /// - the autofilter added by [`Worksheet::add_filtered_header`], extended to the last written row. Its filter
+    ///   rules are kept.
+    pub(crate) fn write_deferred_settings(&self) -> Result<(), XlsxError> {
+        for (worksheet, state) in self.worksheet_state.borrow().iter() {
+            if let Some((header_row, _)) = state.filtered_header {
+                // Adding the autofilter again would remove the filter rules, so only the range is extended
+                unsafe {
+                    (**worksheet).autofilter.last_row = (**worksheet).dim_rowmax.max(header_row);
+                }
+            }
+            if state.show_comments {
+                unsafe {
+                    libxlsxwriter_sys::worksheet_show_comments(*worksheet);
+                }
+            }
+            for (error, ranges) in &state.ignored_errors {
+                let ranges = CString::new(ranges.join(" "))?;
+                unsafe {
+                    let result = libxlsxwriter_sys::worksheet_ignore_errors(
+                        *worksheet,
+                        error.value(),
+                        ranges.as_ptr(),
+                    );
+                    if result != libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                        return Err(XlsxError::new(result));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Set a custom document property. Custom properties are shown in Excel under
+    /// File -> Info -> Properties -> Advanced Properties -> Custom.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let workbook = Workbook::new("test-workbook-set_custom_property.xlsx")?;
+    /// workbook.set_custom_property("Checked by", &"Eve".into())?;
+    /// workbook.set_custom_property("Reference", &CustomPropertyValue::Integer(1008))?;
+    /// workbook.set_custom_property("Approved", &true.into())?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn set_custom_property(
+        &self,
+        name: &str,
+        value: &CustomPropertyValue,
+    ) -> Result<(), XlsxError> {
+        let name = CString::new(name)?;
+        unsafe {
+            let result = match value {
+                CustomPropertyValue::String(value) => {
+                    let value = CString::new(value.as_str())?;
+                    libxlsxwriter_sys::workbook_set_custom_property_string(
+                        self.workbook,
+                        name.as_ptr(),
+                        value.as_ptr(),
+                    )
+                }
+                CustomPropertyValue::Number(value) => {
+                    libxlsxwriter_sys::workbook_set_custom_property_number(
+                        self.workbook,
+                        name.as_ptr(),
+                        *value,
+                    )
+                }
+                CustomPropertyValue::Integer(value) => {
+                    libxlsxwriter_sys::workbook_set_custom_property_integer(
+                        self.workbook,
+                        name.as_ptr(),
+                        *value,
+                    )
+                }
+                CustomPropertyValue::Boolean(value) => {
+                    libxlsxwriter_sys::workbook_set_custom_property_boolean(
+                        self.workbook,
+                        name.as_ptr(),
+                        convert_bool(*value),
+                    )
+                }
+                CustomPropertyValue::DateTime(value) => {
+                    let mut datetime: libxlsxwriter_sys::lxw_datetime = value.into();
+                    libxlsxwriter_sys::workbook_set_custom_property_datetime(
+                        self.workbook,
+                        name.as_ptr(),
+                        &mut datetime,
+                    )
+                }
+            };
+            if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                Ok(())
+            } else {
+                Err(XlsxError::new(result))
+            }
+        }
+    }
+
+    /// Set the standard and the custom document properties of a [`DocumentMetadata`] in one call.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let workbook = Workbook::new("test-workbook-set_metadata.xlsx")?;
+    /// let mut metadata = DocumentMetadata::new();
+    /// metadata
+    ///     .set_title("Quarterly figures")
+    ///     .set_author("Finance team")
+    ///     .add_custom_property("Department", "Controlling");
+    /// workbook.set_metadata(&metadata)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn set_metadata(&self, metadata: &DocumentMetadata) -> Result<(), XlsxError> {
+        self.set_properties(&metadata.properties)?;
+        for (name, value) in &metadata.custom_properties {
+            self.set_custom_property(name, value)?;
+        }
+        Ok(())
+    }
+
     /// The [`Workbook::close`] function closes a Workbook object, writes the Excel file to disk,
     /// frees any memory allocated internally to the Workbook and frees the object itself.
-    pub fn close(mut self) -> Result<(), XlsxError> {
+    ///
+    /// See [`Workbook::close_with_progress`] to get notified when writing a large file starts and ends.
+    pub fn close(self) -> Result<(), XlsxError> {
+        self.close_with_progress(|_| {})
+    }
+
+    /// Close the workbook like [`Workbook::close`], calling `progress` when writing the file starts and when it has
+    /// finished successfully.
+    ///
+    /// libxlsxwriter serializes all worksheets and zips the file in a single synchronous call, so there are no events
+    /// in between. The time taken grows with the number of cells; with `constant_memory` most of the cell data has
+    /// already been written to temporary files when the workbook is closed.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let workbook = Workbook::new("test-workbook-close_with_progress.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # worksheet.write_string(0, 0, "Hello Excel", None)?;
+    /// workbook.close_with_progress(|progress| match progress {
+    ///     CloseProgress::Started { worksheets } => println!("Writing {} worksheets", worksheets),
+    ///     CloseProgress::Finished { elapsed } => println!("Done in {:?}", elapsed),
+    /// })
+    /// # }
+    /// ```
+    pub fn close_with_progress<F: FnMut(CloseProgress)>(
+        mut self,
+        progress: F,
+    ) -> Result<(), XlsxError> {
+        self.close_internal(progress)
+    }
+
+    /// Close a workbook created with [`WorkbookBuilder::build_buffer`] and return the xlsx file.
+    ///
+    /// An error is returned for a workbook that is written to a file. [`Workbook::close`] can be used on a workbook
+    /// created with [`WorkbookBuilder::build_buffer`] to discard the file.
+    pub fn close_buffer(mut self) -> Result<Vec<u8>, XlsxError> {
+        Ok(self.close_output()?.as_bytes().to_vec())
+    }
+
+    /// Close a workbook created with [`WorkbookBuilder::build_buffer`] and write the xlsx file to `writer`, such as a
+    /// network stream or the body of an HTTP response.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let workbook = WorkbookBuilder::new().build_buffer()?;
+    /// let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_string(0, 0, "Hello Excel", None)?;
+    /// let file = std::fs::File::create("test-workbook-close_to_writer-1.xlsx")?;
+    /// workbook.close_to_writer(std::io::BufWriter::new(file))
+    /// # }
+    /// ```
+    /// As with [`Workbook::close_buffer`], an error is returned for a workbook that is written to a file.
+    pub fn close_to_writer<W: Write>(mut self, mut writer: W) -> Result<(), XlsxError> {
+        writer.write_all(self.close_output()?.as_bytes())?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Close a workbook created with [`WorkbookBuilder::build_buffer`] and return the buffer holding the file.
+    fn close_output(&mut self) -> Result<Box<OutputBuffer>, XlsxError> {
+        if self.output_buffer.is_none() {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ));
+        }
+        self.close_internal(|_| {})?;
+        Ok(self.output_buffer.take().unwrap_or_default())
+    }
+
+    fn close_internal<F: FnMut(CloseProgress)>(
+        &mut self,
+        mut progress: F,
+    ) -> Result<(), XlsxError> {
+        let worksheets = unsafe { usize::from((*self.workbook).num_worksheets) };
+        progress(CloseProgress::Started { worksheets });
+        let start = Instant::now();
+        self.write_deferred_settings()?;
         unsafe {
             let result = libxlsxwriter_sys::workbook_close(self.workbook);
             self.workbook = std::ptr::null_mut();
-            match result {
-                libxlsxwriter_sys::lxw_error_LXW_NO_ERROR => Ok(()),
-                _ => Err(XlsxError::new(result)),
+            if result != libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                return Err(XlsxError::new(result));
             }
         }
+        progress(CloseProgress::Finished {
+            elapsed: start.elapsed(),
+        });
+        Ok(())
     }
 
     pub fn add_vba_project(&mut self, path: &str) -> Result<(), XlsxError> {
@@ -296,14 +1290,299 @@ impl Workbook {
             Err(XlsxError::new(res))
         }
     }
+
+    /// Add a signed VBA project, with the `vbaProject.bin` file at `project_path` and its `vbaProjectSignature.bin`
+    /// file at `signature_path`. Both files can be extracted from a signed `.xlsm` file created by Excel.
+    ///
+    /// As with [`Workbook::add_vba_project`], the workbook should be saved with the `.xlsm` extension.
+    /// ```rust,no_run
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let mut workbook = Workbook::new("test-workbook-add_signed_vba_project.xlsm")?;
+    /// workbook.add_signed_vba_project("vbaProject.bin", "vbaProjectSignature.bin")?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn add_signed_vba_project(
+        &mut self,
+        project_path: &str,
+        signature_path: &str,
+    ) -> Result<(), XlsxError> {
+        let mut c_string_helper = CStringHelper::new();
+        unsafe {
+            let result = libxlsxwriter_sys::workbook_add_signed_vba_project(
+                self.workbook,
+                c_string_helper.add(project_path)?,
+                c_string_helper.add(signature_path)?,
+            );
+            if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                Ok(())
+            } else {
+                Err(XlsxError::new(result))
+            }
+        }
+    }
 }
 
 impl Drop for Workbook {
     fn drop(&mut self) {
         unsafe {
             if !self.workbook.is_null() {
+                let _ = self.write_deferred_settings();
                 libxlsxwriter_sys::workbook_close(self.workbook);
             }
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::format::{FormatBorder, FormatColor};
+
+    /// Number of styles added to the libxlsxwriter workbook.
+    fn style_count(workbook: &Workbook) -> usize {
+        let mut count = 0;
+        unsafe {
+            let mut format = (*(*workbook.workbook).formats).stqh_first;
+            while !format.is_null() {
+                count += 1;
+                format = (*format).list_pointers.stqe_next;
+            }
+        }
+        count
+    }
+
+    #[test]
+    fn test_debug_style_info() -> Result<(), XlsxError> {
+        let workbook = Workbook::new("test-workbook-debug_style_info.xlsx")?;
+        let mut worksheet = workbook.add_worksheet(None)?;
+        let base_count = style_count(&workbook);
+        assert_eq!(workbook.debug_style_info(), StyleInfo::default());
+
+        let mut bold = Format::new();
+        bold.set_bold().set_border(FormatBorder::Thin);
+        let mut red = Format::new();
+        red.set_bold().set_bg_color(FormatColor::Red);
+        for row in 0..10 {
+            worksheet.write_number(row, 0, row.into(), Some(&bold))?;
+            worksheet.write_number(row, 1, row.into(), Some(&red))?;
+            worksheet.write_string(row, 2, "text", Some(Format::new().set_bold()))?;
+        }
+        let expected = StyleInfo {
+            formats: 3,
+            fonts: 1,
+            fills: 1,
+            borders: 1,
+        };
+        assert_eq!(workbook.debug_style_info(), expected);
+        assert_eq!(style_count(&workbook), base_count + expected.formats);
+
+        for row in 10..20 {
+            worksheet.write_number(row, 0, row.into(), Some(&bold))?;
+            worksheet.write_number(row, 1, row.into(), Some(&red.clone()))?;
+        }
+        assert_eq!(workbook.debug_style_info(), expected);
+        workbook.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_intern_format() -> Result<(), XlsxError> {
+        let workbook = Workbook::new("test-workbook-intern_format.xlsx")?;
+        let mut worksheet = workbook.add_worksheet(None)?;
+        let base_count = style_count(&workbook);
+
+        let mut bold = Format::new();
+        bold.set_bold();
+        let first = workbook.intern_format(bold.clone())?;
+        let second = workbook.intern_format(bold.clone())?;
+        assert!(Rc::ptr_eq(&first.format, &second.format));
+        assert_eq!(*first, bold);
+        assert_eq!(style_count(&workbook), base_count + 1);
+
+        for row in 0..10 {
+            worksheet.write_number(row, 0, row.into(), Some(&first))?;
+            worksheet.write_number(row, 1, row.into(), Some(&bold))?;
+        }
+        assert_eq!(style_count(&workbook), base_count + 1);
+
+        let mut italic = Format::new();
+        italic.set_italic();
+        let italic = workbook.intern_format(italic)?;
+        assert_ne!(italic, first);
+        assert_eq!(style_count(&workbook), base_count + 2);
+        workbook.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_workbook_builder() -> Result<(), XlsxError> {
+        let mut properties = DocProperties::new();
+        properties.title = Some("Builder".to_string());
+        let mut builder = WorkbookBuilder::new();
+        builder
+            .constant_memory(true)
+            .tmpdir(&std::env::temp_dir().to_string_lossy())
+            .use_zip64(true)
+            .properties(properties);
+        assert!(builder.options.constant_memory);
+        assert!(builder.options.use_zip64);
+
+        let workbook = builder.build("test-workbook-builder.xlsx")?;
+        unsafe {
+            assert_eq!((*workbook.workbook).options.constant_memory, 1);
+            assert_eq!((*workbook.workbook).options.use_zip64, 1);
+        }
+        let mut worksheet = workbook.add_worksheet(None)?;
+        worksheet.write_string(0, 0, "Hello", None)?;
+        workbook.close()?;
+
+        let workbook = WorkbookBuilder::new().build_buffer()?;
+        let mut worksheet = workbook.add_worksheet(None)?;
+        worksheet.write_number(0, 0, 1.0, None)?;
+        let bytes = workbook.close_buffer()?;
+        assert!(bytes.starts_with(b"PK\x03\x04"));
+
+        let workbook = Workbook::new("test-workbook-builder-close_buffer.xlsx")?;
+        assert!(workbook.close_buffer().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_font_size_validation() -> Result<(), XlsxError> {
+        let workbook = Workbook::new("test-workbook-font_size_validation.xlsx")?;
+        let mut worksheet = workbook.add_worksheet(None)?;
+
+        let mut half_point = Format::new();
+        half_point.set_font_size(10.5);
+        worksheet.write_string(0, 0, "10.5 points", Some(&half_point))?;
+        unsafe {
+            assert_eq!(
+                (*workbook.get_internal_format(&half_point)?).font_size,
+                10.5
+            );
+        }
+
+        let styles = style_count(&workbook);
+        let mut too_large = Format::new();
+        too_large.set_font_size(500.0);
+        let error = worksheet
+            .write_string(1, 0, "500 points", Some(&too_large))
+            .unwrap_err();
+        assert_eq!(
+            error.source,
+            crate::error::XlsxErrorSource::InvalidFontSize(500.0)
+        );
+        assert_eq!(style_count(&workbook), styles);
+        assert!(workbook.set_default_format(&too_large).is_err());
+
+        let mut too_small = Format::new();
+        too_small.set_font_size(0.5);
+        assert!(workbook.get_internal_format(&too_small).is_err());
+        workbook.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_active_worksheet() -> Result<(), XlsxError> {
+        let workbook = Workbook::new("test-workbook-set_active_worksheet.xlsx")?;
+        let data = workbook.add_worksheet(Some("Data"))?;
+        let summary = workbook.add_worksheet(Some("Summary"))?;
+        workbook.set_active_worksheet(&summary)?;
+        workbook.set_first_visible_worksheet(&summary)?;
+        unsafe {
+            assert_eq!((*workbook.workbook).active_sheet, 1);
+            assert_eq!((*workbook.workbook).first_sheet, 1);
+            assert_eq!((*summary.worksheet).selected, 1);
+            assert_eq!((*data.worksheet).active, 0);
+        }
+
+        let other = Workbook::new("test-workbook-set_active_worksheet-other.xlsx")?;
+        let foreign = other.add_worksheet(None)?;
+        assert!(workbook.set_active_worksheet(&foreign).is_err());
+        assert!(workbook.set_first_visible_worksheet(&foreign).is_err());
+        other.close()?;
+        workbook.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_buffer() -> Result<(), XlsxError> {
+        let workbook = Workbook::new_buffer()?;
+        let mut worksheet = workbook.add_worksheet(None)?;
+        worksheet.write_string(0, 0, "in memory", None)?;
+        assert!(workbook.close_buffer()?.starts_with(b"PK\x03\x04"));
+        Ok(())
+    }
+
+    #[cfg(feature = "use-fmemopen")]
+    #[test]
+    fn test_new_buffer_without_tmpdir() -> Result<(), XlsxError> {
+        // A temp directory that doesn't exist makes any use of temporary files fail
+        let options = WorkbookOptions {
+            tmpdir: Some("target/no-such-directory".to_string()),
+            ..WorkbookOptions::new()
+        };
+        let workbook = Workbook::create(None, &options, Some(Box::default()))?;
+        let mut worksheet = workbook.add_worksheet(None)?;
+        worksheet.write_string(0, 0, "in memory", None)?;
+        assert!(workbook.close_buffer()?.starts_with(b"PK\x03\x04"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_close_to_writer() -> Result<(), XlsxError> {
+        let workbook = WorkbookBuilder::new().build_buffer()?;
+        let mut worksheet = workbook.add_worksheet(None)?;
+        worksheet.write_string(0, 0, "streamed", None)?;
+        let mut output: Vec<u8> = Vec::new();
+        workbook.close_to_writer(&mut output)?;
+        assert!(output.starts_with(b"PK\x03\x04"));
+
+        let workbook = Workbook::new("test-workbook-close_to_writer.xlsx")?;
+        assert!(workbook.close_to_writer(Vec::new()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_default_format() -> Result<(), XlsxError> {
+        let workbook = Workbook::new("test-workbook-set_default_format.xlsx")?;
+        let mut worksheet = workbook.add_worksheet(None)?;
+        let mut bold = Format::new();
+        bold.set_bold();
+        let mut courier = Format::new();
+        courier.set_font_name("Courier New");
+        worksheet.write_string(0, 1, "Bold", Some(&bold))?;
+
+        workbook.set_default_format(Format::new().set_font_name("Arial").set_font_size(10.))?;
+        worksheet.write_string(0, 0, "Default", None)?;
+        worksheet.write_string(0, 2, "Courier", Some(&courier))?;
+
+        let font = |format: *mut libxlsxwriter_sys::lxw_format| unsafe {
+            (
+                std::ffi::CStr::from_ptr((*format).font_name.as_ptr())
+                    .to_str()
+                    .unwrap()
+                    .to_string(),
+                (*format).font_size,
+            )
+        };
+        unsafe {
+            assert_eq!(
+                font((*workbook.workbook).default_format),
+                ("Arial".to_string(), 10.)
+            );
+        }
+        assert_eq!(
+            font(workbook.get_internal_format(&bold)?),
+            ("Arial".to_string(), 10.)
+        );
+        assert_eq!(
+            font(workbook.get_internal_format(&courier)?),
+            ("Courier New".to_string(), 10.)
+        );
+        workbook.close()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+use super::{DateTime, Worksheet, WorksheetCol, WorksheetRow, COL_COUNT, ROW_COUNT};
+use crate::{Format, XlsxError};
+
+/// A value of any type that can be written to a cell with [`Worksheet::write_value`].
+///
+/// Most values can be converted with `into()`:
+/// ```rust
+/// # use xlsxwriter::prelude::*;
+/// let values: Vec<CellValue> = vec!["text".into(), 1.5.into(), 42.into(), true.into()];
+/// assert_eq!(values[1], CellValue::Number(1.5));
+/// ```
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+pub enum CellValue {
+    String(String),
+    Number(f64),
+    Boolean(bool),
+    /// Date and time values need a format with a date number format, such as `yyyy-mm-dd`, to be shown as dates.
+    DateTime(DateTime),
+    /// A formula such as `=SUM(A1:A10)`, written with [`Worksheet::write_formula`].
+    Formula(String),
+    #[default]
+    Blank,
+}
+
+impl From<&str> for CellValue {
+    fn from(val: &str) -> Self {
+        CellValue::String(val.to_string())
+    }
+}
+
+impl From<String> for CellValue {
+    fn from(val: String) -> Self {
+        CellValue::String(val)
+    }
+}
+
+impl From<f64> for CellValue {
+    fn from(val: f64) -> Self {
+        CellValue::Number(val)
+    }
+}
+
+impl From<f32> for CellValue {
+    fn from(val: f32) -> Self {
+        CellValue::Number(val.into())
+    }
+}
+
+impl From<i32> for CellValue {
+    fn from(val: i32) -> Self {
+        CellValue::Number(val.into())
+    }
+}
+
+impl From<u32> for CellValue {
+    fn from(val: u32) -> Self {
+        CellValue::Number(val.into())
+    }
+}
+
+/// Integers above 2^53 lose precision, because Excel stores every number as a 64 bit float.
+impl From<i64> for CellValue {
+    fn from(val: i64) -> Self {
+        CellValue::Number(val as f64)
+    }
+}
+
+impl From<bool> for CellValue {
+    fn from(val: bool) -> Self {
+        CellValue::Boolean(val)
+    }
+}
+
+impl From<DateTime> for CellValue {
+    fn from(val: DateTime) -> Self {
+        CellValue::DateTime(val)
+    }
+}
+
+impl<T: Into<CellValue>> From<Option<T>> for CellValue {
+    fn from(val: Option<T>) -> Self {
+        val.map_or(CellValue::Blank, Into::into)
+    }
+}
+
+impl<'a> Worksheet<'a> {
+    /// Write a [`CellValue`] with the `write_*` function matching its type.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_value-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_value(0, 0, &"Total".into(), None)?;
+    /// worksheet.write_value(0, 1, &CellValue::Formula("=SUM(B2:B10)".to_string()), None)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// [`CellValue::Blank`] is written with [`Worksheet::write_blank`], so it is only written if a format is given.
+    pub fn write_value(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        value: &CellValue,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        match value {
+            CellValue::String(text) => self.write_string(row, col, text, format),
+            CellValue::Number(number) => self.write_number(row, col, *number, format),
+            CellValue::Boolean(value) => self.write_boolean(row, col, *value, format),
+            CellValue::DateTime(datetime) => self.write_datetime(row, col, datetime, format),
+            CellValue::Formula(formula) => self.write_formula(row, col, formula, format),
+            CellValue::Blank => self.write_blank(row, col, format),
+        }
+    }
+
+    /// Write values to consecutive cells of a row, starting at `first_col`.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_row-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_row(0, 0, &["Apples".into(), 12.into(), true.into()], None)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// An error is returned without writing anything if the values do not fit in the row.
+    pub fn write_row(
+        &mut self,
+        row: WorksheetRow,
+        first_col: WorksheetCol,
+        values: &[CellValue],
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        if u64::from(first_col) + values.len() as u64 > u64::from(COL_COUNT) {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_WORKSHEET_INDEX_OUT_OF_RANGE,
+            ));
+        }
+        for (col, value) in (first_col..).zip(values.iter()) {
+            self.write_value(row, col, value, format)?;
+        }
+        Ok(())
+    }
+
+    /// Write values to consecutive cells of a column, starting at `first_row`.
+    ///
+    /// An error is returned without writing anything if the values do not fit in the column.
+    pub fn write_column(
+        &mut self,
+        first_row: WorksheetRow,
+        col: WorksheetCol,
+        values: &[CellValue],
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        if u64::from(first_row) + values.len() as u64 > u64::from(ROW_COUNT) {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_WORKSHEET_INDEX_OUT_OF_RANGE,
+            ));
+        }
+        for (row, value) in (first_row..).zip(values.iter()) {
+            self.write_value(row, col, value, format)?;
+        }
+        Ok(())
+    }
+
+    /// Write a block of label/value pairs, with the labels in `start_col` and the values in the column to the right.
+    /// This is useful for summary panels and metadata at the top of a report.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_key_values-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_key_values(
+    ///     0,
+    ///     0,
+    ///     &[("Report", "Monthly sales".into()), ("Rows", 1200.into()), ("Final", true.into())],
+    ///     Some(Format::new().set_bold()),
+    ///     None,
+    /// )?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn write_key_values(
+        &mut self,
+        start_row: WorksheetRow,
+        start_col: WorksheetCol,
+        pairs: &[(&str, CellValue)],
+        key_format: Option<&Format>,
+        value_format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        for (row, (key, value)) in (start_row..).zip(pairs.iter()) {
+            self.write_string(row, start_col, key, key_format)?;
+            self.write_value(row, start_col + 1, value, value_format)?;
+        }
+        Ok(())
+    }
+
+    /// Write a grid of values, with an optional header row above it. Each inner `Vec` is one row, and rows may
+    /// have different lengths. The body starts below the header, or at `start_row` if there is no header.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_table_data-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let rows: Vec<Vec<CellValue>> = vec![
+    ///     vec!["Apples".into(), 12.into()],
+    ///     vec!["Pears".into(), 7.5.into()],
+    /// ];
+    /// worksheet.write_table_data(0, 0, &rows, Some(&["Fruit", "Amount"]), Some(Format::new().set_bold()), None)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn write_table_data(
+        &mut self,
+        start_row: WorksheetRow,
+        start_col: WorksheetCol,
+        rows: &[Vec<CellValue>],
+        header: Option<&[&str]>,
+        header_format: Option<&Format>,
+        body_format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        let mut body_row = start_row;
+        if let Some(header) = header {
+            for (col, title) in (start_col..).zip(header.iter()) {
+                self.write_string(start_row, col, title, header_format)?;
+            }
+            body_row += 1;
+        }
+        for (row, values) in (body_row..).zip(rows.iter()) {
+            for (col, value) in (start_col..).zip(values.iter()) {
+                self.write_value(row, col, value, body_format)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -21,14 +21,20 @@ pub enum ConditionalFormatCellCriteria {
     GreaterThanOrEqualTo(StringOrFloat),
     /// Format cells less than or equal to a value.
     LessThanOrEqualTo(StringOrFloat),
-    /// Format cells between two values.
+    /// Format cells between two values, including the values themselves.
+    /// See usage at [`ConditionalFormat::cell_between`]
     Between {
+        /// Lower bound, passed to libxlsxwriter as the minimum value of the rule.
         min: StringOrFloat,
+        /// Upper bound, passed to libxlsxwriter as the maximum value of the rule.
         max: StringOrFloat,
     },
     /// Format cells that is not between two values.
+    /// See usage at [`ConditionalFormat::cell_not_between`]
     NotBetween {
+        /// Lower bound, passed to libxlsxwriter as the minimum value of the rule.
         min: StringOrFloat,
+        /// Upper bound, passed to libxlsxwriter as the maximum value of the rule.
         max: StringOrFloat,
     },
 }
@@ -129,6 +135,7 @@ impl ConditionalFormat {
                 value.into(),
             )),
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 
@@ -160,6 +167,7 @@ impl ConditionalFormat {
                 value.into(),
             )),
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 
@@ -191,9 +199,34 @@ impl ConditionalFormat {
                 value.into(),
             )),
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 
+    /// Highlight cells greater than a value, the most common dashboard rule. This is the same rule as
+    /// [`ConditionalFormat::cell_greater_than`].
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # use xlsxwriter::worksheet::conditional_format::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-conditional_format-highlight_greater_than.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..10 {
+    /// #     worksheet.write_number(i, 0, i.into(), None)?;
+    /// # }
+    /// let mut highlight = Format::new();
+    /// highlight.set_bg_color(FormatColor::Custom(0xFFC7CE)).set_font_color(FormatColor::Custom(0x9C0006));
+    /// worksheet.conditional_format_range(0, 0, 9, 0, &ConditionalFormat::highlight_greater_than(5., &highlight))?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn highlight_greater_than<V: Into<StringOrFloat>>(
+        value: V,
+        format: &Format,
+    ) -> ConditionalFormat {
+        ConditionalFormat::cell_greater_than(value, format)
+    }
+
     /// Format cells less than a value.
     ///
     /// ```rust
@@ -219,6 +252,7 @@ impl ConditionalFormat {
                 value.into(),
             )),
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 
@@ -250,6 +284,7 @@ impl ConditionalFormat {
                 ConditionalFormatCellCriteria::GreaterThanOrEqualTo(value.into()),
             ),
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 
@@ -281,6 +316,7 @@ impl ConditionalFormat {
                 ConditionalFormatCellCriteria::LessThanOrEqualTo(value.into()),
             ),
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 
@@ -314,6 +350,7 @@ impl ConditionalFormat {
                 max: max_value.into(),
             }),
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 
@@ -347,6 +384,7 @@ impl ConditionalFormat {
                 max: max_value.into(),
             }),
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 }
@@ -417,4 +455,50 @@ mod test {
         workbook.close()?;
         Ok(())
     }
+
+    #[test]
+    fn test_worksheet_conditional_format_between() -> Result<(), XlsxError> {
+        let workbook = Workbook::new("test-worksheet_conditional-format_between.xlsx")?;
+        let mut worksheet = workbook.add_worksheet(None)?;
+        for i in 0..30 {
+            worksheet.write_number(i, 0, i.into(), None)?;
+            worksheet.write_number(i, 1, i.into(), None)?;
+        }
+        let mut highlight = Format::new();
+        highlight.set_bg_color(FormatColor::Yellow);
+        let between = ConditionalFormat::cell_between(10.0, 20.0, &highlight);
+        let not_between = ConditionalFormat::cell_not_between(10.0, "=$C$1", &highlight);
+        worksheet.conditional_format_range(0, 0, 29, 0, &between)?;
+        worksheet.conditional_format_range(0, 1, 29, 1, &not_between)?;
+
+        let criteria = |conditional_format: &ConditionalFormat| match conditional_format {
+            ConditionalFormat::ConditionType {
+                criteria: ConditionalFormatTypes::Cell(criteria),
+                ..
+            } => criteria.clone(),
+            _ => unreachable!(),
+        };
+        let mut c_string_helper = CStringHelper::new();
+        let mut internal: libxlsxwriter_sys::lxw_conditional_format = unsafe { std::mem::zeroed() };
+        criteria(&between).to_internal_value(&mut c_string_helper, &mut internal)?;
+        assert_eq!(
+            internal.criteria,
+            libxlsxwriter_sys::lxw_conditional_criteria_LXW_CONDITIONAL_CRITERIA_BETWEEN as u8
+        );
+        assert_eq!((internal.min_value, internal.max_value), (10.0, 20.0));
+
+        let mut internal: libxlsxwriter_sys::lxw_conditional_format = unsafe { std::mem::zeroed() };
+        criteria(&not_between).to_internal_value(&mut c_string_helper, &mut internal)?;
+        assert_eq!(
+            internal.criteria,
+            libxlsxwriter_sys::lxw_conditional_criteria_LXW_CONDITIONAL_CRITERIA_NOT_BETWEEN as u8
+        );
+        assert_eq!(internal.min_value, 10.0);
+        assert_eq!(
+            unsafe { std::ffi::CStr::from_ptr(internal.max_value_string) }.to_str(),
+            Ok("=$C$1")
+        );
+        workbook.close()?;
+        Ok(())
+    }
 }
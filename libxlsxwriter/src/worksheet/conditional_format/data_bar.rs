@@ -217,6 +217,27 @@ impl ConditionalDataBar {
 }
 
 impl ConditionalFormat {
+    /// Preset of a gradient data bar in Excel's default blue, scaled from the lowest to the highest value of the range.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # use xlsxwriter::worksheet::conditional_format::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-conditional_format-data_bar_blue.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..10 {
+    /// #     worksheet.write_number(i, 0, i.into(), None)?;
+    /// # }
+    /// worksheet.conditional_format_range(0, 0, 9, 0, &ConditionalFormat::data_bar_blue())?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn data_bar_blue() -> ConditionalFormat {
+        ConditionalFormat::data_bar(
+            ConditionalDataBar::new().color(Some(FormatColor::Custom(0x638EC6))),
+        )
+    }
+
     /// Data Bar
     ///
     /// Example:
@@ -49,8 +49,8 @@ pub use time_period::*;
 pub use two_color::*;
 
 use crate::{
-    convert_bool, CStringHelper, Format, StringOrFloat, Workbook, Worksheet, WorksheetCol,
-    WorksheetRow, XlsxError,
+    convert_bool, CStringHelper, CellRange, Format, StringOrFloat, Workbook, Worksheet,
+    WorksheetCol, WorksheetRow, XlsxError,
 };
 use std::os::raw::c_char;
 
@@ -294,6 +294,8 @@ pub enum ConditionalFormat {
     ConditionType {
         criteria: ConditionalFormatTypes,
         format: Format,
+        /// Stop evaluating the rules below this one when this rule matches. See [`ConditionalFormat::set_stop_if_true`].
+        stop_if_true: bool,
     },
     TwoColorScale(TwoColorScaleCriteria),
     ThreeColorScale(ThreeColorScaleCriteria),
@@ -333,6 +335,7 @@ impl ConditionalFormat {
         ConditionalFormat::ConditionType {
             criteria: ConditionalFormatTypes::Average(average),
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 
@@ -361,6 +364,7 @@ impl ConditionalFormat {
         ConditionalFormat::ConditionType {
             criteria: ConditionalFormatTypes::Duplicate,
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 
@@ -389,6 +393,7 @@ impl ConditionalFormat {
         ConditionalFormat::ConditionType {
             criteria: ConditionalFormatTypes::Unique,
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 
@@ -418,6 +423,7 @@ impl ConditionalFormat {
         ConditionalFormat::ConditionType {
             criteria: ConditionalFormatTypes::Top(TopOrBottomCriteria::TopOrBottomNum(num)),
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 
@@ -447,6 +453,7 @@ impl ConditionalFormat {
         ConditionalFormat::ConditionType {
             criteria: ConditionalFormatTypes::Top(TopOrBottomCriteria::TopOrBottomPercent(percent)),
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 
@@ -475,6 +482,7 @@ impl ConditionalFormat {
         ConditionalFormat::ConditionType {
             criteria: ConditionalFormatTypes::Blanks,
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 
@@ -503,6 +511,7 @@ impl ConditionalFormat {
         ConditionalFormat::ConditionType {
             criteria: ConditionalFormatTypes::NoBlanks,
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 
@@ -532,6 +541,7 @@ impl ConditionalFormat {
         ConditionalFormat::ConditionType {
             criteria: ConditionalFormatTypes::Errors,
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 
@@ -561,6 +571,7 @@ impl ConditionalFormat {
         ConditionalFormat::ConditionType {
             criteria: ConditionalFormatTypes::NoErrors,
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 
@@ -590,9 +601,43 @@ impl ConditionalFormat {
         ConditionalFormat::ConditionType {
             criteria: ConditionalFormatTypes::Formula(formula.to_string()),
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 
+    /// Set the "Stop If True" option of a rule. When a cell matches a rule with this option, the conditional formats
+    /// added to the same range after it are not evaluated, which allows rules to be layered.
+    ///
+    /// Excel only supports this option on rules with a format, so it is ignored for color scales, data bars and icon sets.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # use xlsxwriter::worksheet::conditional_format::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-conditional_format-stop_if_true.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..30 {
+    /// #     worksheet.write_number(i, 0, i.into(), None)?;
+    /// # }
+    /// worksheet.conditional_format_range(
+    ///     0, 0, 29, 0,
+    ///     ConditionalFormat::cell_greater_than(20., Format::new().set_bg_color(FormatColor::Red))
+    ///         .set_stop_if_true(true),
+    /// )?;
+    /// // Not applied to cells greater than 20.
+    /// worksheet.conditional_format_range(
+    ///     0, 0, 29, 0,
+    ///     &ConditionalFormat::cell_greater_than(10., Format::new().set_bg_color(FormatColor::Yellow)),
+    /// )?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn set_stop_if_true(&mut self, value: bool) -> &mut Self {
+        if let ConditionalFormat::ConditionType { stop_if_true, .. } = self {
+            *stop_if_true = value;
+        }
+        self
+    }
+
     pub(crate) fn to_internal_type(
         &self,
         workbook: &Workbook,
@@ -636,9 +681,14 @@ impl ConditionalFormat {
             stop_if_true: convert_bool(false),
         };
         match self {
-            ConditionalFormat::ConditionType { criteria, format } => {
+            ConditionalFormat::ConditionType {
+                criteria,
+                format,
+                stop_if_true,
+            } => {
                 let internal_format = workbook.get_internal_format(format)?;
                 conditional_format.format = internal_format;
+                conditional_format.stop_if_true = convert_bool(*stop_if_true);
                 criteria.to_internal_value(c_string_helper, &mut conditional_format)?;
             }
             ConditionalFormat::TwoColorScale(criteria) => {
@@ -715,6 +765,42 @@ impl<'a> Worksheet<'a> {
             }
         }
     }
+
+    /// Same as [`Worksheet::conditional_format_range`] but the range is given as an A1 style string such as `"A1:A100"`.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # use xlsxwriter::worksheet::conditional_format::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-conditional_format_a1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.conditional_format_a1(
+    ///     "B2:B20",
+    ///     &ConditionalFormat::two_color_scale(
+    ///         ConditionalFormatRuleTypes::Minimum,
+    ///         ConditionalFormatRuleTypes::Maximum,
+    ///         0.,
+    ///         0.,
+    ///         FormatColor::White,
+    ///         FormatColor::Green,
+    ///     ),
+    /// )?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn conditional_format_a1(
+        &mut self,
+        range: &str,
+        conditional_format: &ConditionalFormat,
+    ) -> Result<(), XlsxError> {
+        let range = CellRange::from_a1(range)?;
+        self.conditional_format_range(
+            range.first_row,
+            range.first_col,
+            range.last_row,
+            range.last_col,
+            conditional_format,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -745,4 +831,119 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_worksheet_conditional_format_stop_if_true() -> Result<(), XlsxError> {
+        let workbook = Workbook::new("test-worksheet_conditional-format_stop_if_true.xlsx")?;
+        let mut worksheet = workbook.add_worksheet(None)?;
+        for i in 0..=100 {
+            worksheet.write_number(i, 0, i.into(), None)?;
+        }
+        let mut red = Format::new();
+        red.set_bg_color(FormatColor::Red);
+        let mut yellow = Format::new();
+        yellow.set_bg_color(FormatColor::Yellow);
+
+        let mut first = ConditionalFormat::cell_greater_than(80., &red);
+        first.set_stop_if_true(true);
+        assert!(matches!(
+            first,
+            ConditionalFormat::ConditionType {
+                stop_if_true: true,
+                ..
+            }
+        ));
+        worksheet.conditional_format_range(0, 0, 100, 0, &first)?;
+        worksheet.conditional_format_range(
+            0,
+            0,
+            100,
+            0,
+            &ConditionalFormat::cell_greater_than(50., &yellow),
+        )?;
+        workbook.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_worksheet_conditional_format_presets() -> Result<(), XlsxError> {
+        let workbook = Workbook::new("test-worksheet_conditional-format_presets.xlsx")?;
+        let mut worksheet = workbook.add_worksheet(None)?;
+        for i in 0..20 {
+            for j in 0..3 {
+                worksheet.write_number(i, j, i.into(), None)?;
+            }
+        }
+        let mut highlight = Format::new();
+        highlight.set_bg_color(FormatColor::Custom(0xFFC7CE));
+
+        let mut c_string_helper = CStringHelper::new();
+        let data_bar = ConditionalFormat::data_bar_blue();
+        let internal = data_bar.to_internal_type(&workbook, &mut c_string_helper)?;
+        assert_eq!(
+            internal.type_,
+            libxlsxwriter_sys::lxw_conditional_format_types_LXW_CONDITIONAL_DATA_BAR as u8
+        );
+        assert_eq!(internal.bar_color, 0x638EC6);
+        worksheet.conditional_format_range(0, 0, 19, 0, &data_bar)?;
+
+        let color_scale = ConditionalFormat::color_scale_green_yellow_red();
+        let internal = color_scale.to_internal_type(&workbook, &mut c_string_helper)?;
+        assert_eq!(
+            internal.type_,
+            libxlsxwriter_sys::lxw_conditional_format_types_LXW_CONDITIONAL_3_COLOR_SCALE as u8
+        );
+        assert_eq!(
+            (internal.min_color, internal.mid_color, internal.max_color),
+            (0xF8696B, 0xFFEB84, 0x63BE7B)
+        );
+        worksheet.conditional_format_range(0, 1, 19, 1, &color_scale)?;
+
+        let greater_than = ConditionalFormat::highlight_greater_than(10., &highlight);
+        assert!(greater_than == ConditionalFormat::cell_greater_than(10., &highlight));
+        let internal = greater_than.to_internal_type(&workbook, &mut c_string_helper)?;
+        assert_eq!(internal.value, 10.);
+        assert_eq!(internal.format, workbook.get_internal_format(&highlight)?);
+        worksheet.conditional_format_range(0, 2, 19, 2, &greater_than)?;
+        workbook.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_worksheet_conditional_format_a1() -> Result<(), XlsxError> {
+        let workbook = Workbook::new("test-worksheet_conditional-format_a1.xlsx")?;
+        let mut worksheet = workbook.add_worksheet(None)?;
+        for i in 0..100 {
+            worksheet.write_number(i, 0, i.into(), None)?;
+        }
+        worksheet.conditional_format_a1(
+            "A1:A100",
+            &ConditionalFormat::three_color_scale(
+                ConditionalFormatRuleTypes::Minimum,
+                ConditionalFormatRuleTypes::Percentile,
+                ConditionalFormatRuleTypes::Maximum,
+                0.,
+                50.,
+                0.,
+                FormatColor::Red,
+                FormatColor::Yellow,
+                FormatColor::Green,
+            ),
+        )?;
+        assert!(worksheet
+            .conditional_format_a1(
+                "A1:",
+                &ConditionalFormat::two_color_scale(
+                    ConditionalFormatRuleTypes::Minimum,
+                    ConditionalFormatRuleTypes::Maximum,
+                    0.,
+                    0.,
+                    FormatColor::White,
+                    FormatColor::Green,
+                ),
+            )
+            .is_err());
+        workbook.close()?;
+        Ok(())
+    }
 }
@@ -76,6 +76,7 @@ impl ConditionalFormat {
                 value.to_string(),
             )),
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 
@@ -106,6 +107,7 @@ impl ConditionalFormat {
                 value.to_string(),
             )),
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 
@@ -136,6 +138,7 @@ impl ConditionalFormat {
                 value.to_string(),
             )),
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 
@@ -166,6 +169,7 @@ impl ConditionalFormat {
                 value.to_string(),
             )),
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 }
@@ -135,7 +135,6 @@ impl ConditionalFormat {
     /// # Ok(())
     /// # }
     /// ```
-
     pub fn three_color_scale<
         V1: Into<StringOrFloat>,
         V2: Into<StringOrFloat>,
@@ -163,4 +162,37 @@ impl ConditionalFormat {
             max_color,
         })
     }
+
+    /// Preset of Excel's "Green - Yellow - Red" color scale: the lowest values are red, the median is yellow and the
+    /// highest values are green.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # use xlsxwriter::worksheet::conditional_format::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-conditional_format-color_scale_green_yellow_red.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..10 {
+    /// #     worksheet.write_number(i, 0, i.into(), None)?;
+    /// # }
+    /// worksheet.conditional_format_range(
+    ///     0, 0, 9, 0,
+    ///     &ConditionalFormat::color_scale_green_yellow_red(),
+    /// )?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn color_scale_green_yellow_red() -> ConditionalFormat {
+        ConditionalFormat::three_color_scale(
+            ConditionalFormatRuleTypes::Minimum,
+            ConditionalFormatRuleTypes::Percentile,
+            ConditionalFormatRuleTypes::Maximum,
+            0.,
+            50.,
+            0.,
+            FormatColor::Custom(0xF8696B),
+            FormatColor::Custom(0xFFEB84),
+            FormatColor::Custom(0x63BE7B),
+        )
+    }
 }
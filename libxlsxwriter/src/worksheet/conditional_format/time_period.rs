@@ -100,6 +100,7 @@ impl ConditionalFormat {
         ConditionalFormat::ConditionType {
             criteria: ConditionalFormatTypes::TimePeriod(time_period),
             format: format.clone(),
+            stop_if_true: false,
         }
     }
 }
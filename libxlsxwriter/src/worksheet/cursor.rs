@@ -0,0 +1,93 @@
+use super::{CellValue, Worksheet, WorksheetCol, WorksheetRow, COL_COUNT};
+use crate::{Format, XlsxError};
+
+/// A writer that keeps track of the next cell to write, created with [`Worksheet::cursor`].
+///
+/// Values are written from left to right, and [`Cursor::newline`] moves to the start column of the next row.
+/// ```rust
+/// # use xlsxwriter::prelude::*;
+/// # fn main() -> Result<(), XlsxError> {
+/// # let workbook = Workbook::new("test-worksheet_cursor-1.xlsx")?;
+/// # let mut worksheet = workbook.add_worksheet(None)?;
+/// let mut cursor = worksheet.cursor(1, 1);
+/// cursor.write_row(&["Name".into(), "Score".into()], None)?;
+/// cursor.write_row(&["Alice".into(), 92.into()], None)?;
+/// cursor.write(&"Bob".into(), None)?.write(&87.into(), None)?;
+/// cursor.newline();
+/// assert_eq!(cursor.position(), (4, 1));
+/// # workbook.close()
+/// # }
+/// ```
+pub struct Cursor<'w, 'a> {
+    worksheet: &'w mut Worksheet<'a>,
+    start_col: WorksheetCol,
+    row: WorksheetRow,
+    col: WorksheetCol,
+}
+
+impl<'w, 'a> Cursor<'w, 'a> {
+    /// Row and column of the next cell to write.
+    pub fn position(&self) -> (WorksheetRow, WorksheetCol) {
+        (self.row, self.col)
+    }
+
+    /// Row of the next cell to write.
+    pub fn row(&self) -> WorksheetRow {
+        self.row
+    }
+
+    /// Column of the next cell to write.
+    pub fn col(&self) -> WorksheetCol {
+        self.col
+    }
+
+    /// Write a value to the current cell and move to the cell on its right.
+    pub fn write(
+        &mut self,
+        value: &CellValue,
+        format: Option<&Format>,
+    ) -> Result<&mut Self, XlsxError> {
+        if u32::from(self.col) >= COL_COUNT {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_WORKSHEET_INDEX_OUT_OF_RANGE,
+            ));
+        }
+        self.worksheet
+            .write_value(self.row, self.col, value, format)?;
+        self.col += 1;
+        Ok(self)
+    }
+
+    /// Write values from the current cell to the right with [`Worksheet::write_row`], then move to the start column
+    /// of the next row. The position is not changed if an error is returned.
+    pub fn write_row(
+        &mut self,
+        values: &[CellValue],
+        format: Option<&Format>,
+    ) -> Result<&mut Self, XlsxError> {
+        self.worksheet
+            .write_row(self.row, self.col, values, format)?;
+        self.newline();
+        Ok(self)
+    }
+
+    /// Move to the start column of the next row.
+    pub fn newline(&mut self) -> &mut Self {
+        self.row = self.row.saturating_add(1);
+        self.col = self.start_col;
+        self
+    }
+}
+
+impl<'a> Worksheet<'a> {
+    /// Create a [`Cursor`] that writes values starting at `start_row` and `start_col`, and returns to `start_col` on
+    /// every new row.
+    pub fn cursor(&mut self, start_row: WorksheetRow, start_col: WorksheetCol) -> Cursor<'_, 'a> {
+        Cursor {
+            worksheet: self,
+            start_col,
+            row: start_row,
+            col: start_col,
+        }
+    }
+}
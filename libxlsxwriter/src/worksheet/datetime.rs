@@ -1,7 +1,17 @@
 #[cfg(feature = "chrono")]
-use chrono::{Datelike, Timelike};
+use chrono::{Datelike, TimeZone, Timelike};
 
 use super::DateTime;
+#[cfg(feature = "chrono")]
+use super::{Worksheet, WorksheetCol, WorksheetRow, ROW_COUNT};
+#[cfg(feature = "chrono")]
+use crate::{Format, XlsxError};
+#[cfg(feature = "chrono")]
+use std::convert::TryFrom;
+
+/// Number format of [`Worksheet::write_date`] if no format is given.
+#[cfg(feature = "chrono")]
+const DEFAULT_DATE_FORMAT: &str = "yyyy-mm-dd";
 
 impl DateTime {
     #[must_use]
@@ -83,6 +93,143 @@ impl From<chrono::naive::NaiveTime> for DateTime {
     }
 }
 
+/// Excel stores dates and times without a timezone, so a timezone-aware `chrono::DateTime` has to be converted
+/// to a naive date and time before it is written. The two functions below make the conversion explicit.
+///
+/// To write the time in another zone, such as the zone of the machine, convert it first with
+/// `datetime.with_timezone(&chrono::Local)` and write it with [`Worksheet::write_chrono_datetime_local`].
+#[cfg(feature = "chrono")]
+impl<'a> Worksheet<'a> {
+    /// Write a timezone-aware datetime converted to UTC.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// use chrono::{FixedOffset, TimeZone};
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_chrono_datetime_utc-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+    /// let datetime = tokyo.with_ymd_and_hms(2024, 3, 10, 18, 30, 0).unwrap();
+    /// // Written as 2024-03-10 09:30
+    /// worksheet.write_chrono_datetime_utc(0, 0, &datetime, Some(Format::new().set_num_format("yyyy-mm-dd hh:mm")))?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn write_chrono_datetime_utc<Tz: TimeZone>(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        datetime: &chrono::DateTime<Tz>,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        self.write_datetime(row, col, &datetime.naive_utc().into(), format)
+    }
+
+    /// Write the wall clock time of a timezone-aware datetime in its own timezone. The offset is dropped.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// use chrono::{FixedOffset, TimeZone};
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_chrono_datetime_local-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+    /// let datetime = tokyo.with_ymd_and_hms(2024, 3, 10, 18, 30, 0).unwrap();
+    /// // Written as 2024-03-10 18:30
+    /// worksheet.write_chrono_datetime_local(0, 0, &datetime, Some(Format::new().set_num_format("yyyy-mm-dd hh:mm")))?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn write_chrono_datetime_local<Tz: TimeZone>(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        datetime: &chrono::DateTime<Tz>,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        self.write_datetime(row, col, &datetime.naive_local().into(), format)
+    }
+
+    /// Write a date. Without a format the date is shown as `yyyy-mm-dd`, so that it isn't displayed as the serial
+    /// number Excel stores dates as.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// use chrono::NaiveDate;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_date-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let date = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+    /// worksheet.write_date(0, 0, date, None)?; // 2024-03-10
+    /// worksheet.write_date(1, 0, date, Some(Format::new().set_num_format("d mmm yyyy")))?; // 10 Mar 2024
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// A given format is used as it is, so it needs a date number format to show the date.
+    pub fn write_date(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        date: chrono::NaiveDate,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        let datetime = date.into();
+        match format {
+            Some(format) => self.write_datetime(row, col, &datetime, Some(format)),
+            None => self.write_datetime(
+                row,
+                col,
+                &datetime,
+                Some(Format::new().set_num_format(DEFAULT_DATE_FORMAT)),
+            ),
+        }
+    }
+
+    /// Write `count` dates to a column, starting with `start` at `start_row` and adding `step_days` days for every
+    /// row. The dates are written with [`Worksheet::write_date`], so they are shown as `yyyy-mm-dd` without a format.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// use chrono::NaiveDate;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_date_series-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let monday = NaiveDate::from_ymd_opt(2024, 3, 11).unwrap();
+    /// worksheet.write_date_series(1, 0, monday, 7, 1, None)?; // one week
+    /// worksheet.write_date_series(1, 1, monday, 4, 7, None)?; // four Mondays
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// `step_days` may be negative for a series going backwards. An error is returned without writing anything if the
+    /// series doesn't fit in the column or a date is out of the range supported by chrono.
+    pub fn write_date_series(
+        &mut self,
+        start_row: WorksheetRow,
+        col: WorksheetCol,
+        start: chrono::NaiveDate,
+        count: u32,
+        step_days: i64,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        if u64::from(start_row) + u64::from(count) > u64::from(ROW_COUNT) {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_WORKSHEET_INDEX_OUT_OF_RANGE,
+            ));
+        }
+        let dates = (0..i64::from(count))
+            .map(|i| {
+                step_days
+                    .checked_mul(i)
+                    .and_then(|days| i32::try_from(days).ok())
+                    .and_then(|days| start.checked_add_signed(chrono::Duration::days(days.into())))
+                    .ok_or_else(|| {
+                        XlsxError::new(libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION)
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        for (row, date) in (start_row..).zip(dates) {
+            self.write_date(row, col, date, format)?;
+        }
+        Ok(())
+    }
+}
+
 impl From<&DateTime> for libxlsxwriter_sys::lxw_datetime {
     fn from(datetime: &DateTime) -> Self {
         libxlsxwriter_sys::lxw_datetime {
@@ -1,6 +1,9 @@
 use crate::{
-    try_to_vec, CStringHelper, StringOrFloat, Worksheet, WorksheetCol, WorksheetRow, XlsxError,
+    try_to_vec, CStringHelper, CellValue, Format, StringOrFloat, Worksheet, WorksheetCol,
+    WorksheetRow, XlsxError,
 };
+use std::cmp::Ordering;
+use std::convert::TryFrom;
 
 /// And/or operator conditions when using 2 filter rules with `filter_column2`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -100,6 +103,41 @@ impl FilterRule {
             value: self.value.to_f64().unwrap_or_default(),
         })
     }
+
+    /// Whether Excel shows a cell with `value` when the rule is applied, or `None` if it can't be known without
+    /// calculating the cell, such as for formulas. Strings are compared case-insensitively, without wildcards.
+    fn matches(&self, value: &CellValue) -> Option<bool> {
+        let blank = match value {
+            CellValue::Formula(_) | CellValue::DateTime(_) => return None,
+            CellValue::Blank => true,
+            CellValue::String(x) => x.is_empty(),
+            CellValue::Number(_) | CellValue::Boolean(_) => false,
+        };
+        let ordering = match (value, &self.value) {
+            (CellValue::Number(x), StringOrFloat::Float(y)) => x.partial_cmp(y),
+            (CellValue::String(x), StringOrFloat::String(y)) => {
+                Some(x.to_lowercase().cmp(&y.to_lowercase()))
+            }
+            (CellValue::Boolean(x), StringOrFloat::String(y)) => {
+                Some(if *x { "true" } else { "false" }.cmp(y.to_lowercase().as_str()))
+            }
+            _ => None,
+        };
+        Some(match self.criteria {
+            FilterCriteria::EqualTo => ordering == Some(Ordering::Equal),
+            FilterCriteria::NotEqualTo => ordering != Some(Ordering::Equal),
+            FilterCriteria::GreaterThan => ordering == Some(Ordering::Greater),
+            FilterCriteria::LessThan => ordering == Some(Ordering::Less),
+            FilterCriteria::GreaterThanOrEqualTo => {
+                matches!(ordering, Some(Ordering::Greater | Ordering::Equal))
+            }
+            FilterCriteria::LessThanOrEqualTo => {
+                matches!(ordering, Some(Ordering::Less | Ordering::Equal))
+            }
+            FilterCriteria::Blanks => blank,
+            FilterCriteria::NonBlanks => !blank,
+        })
+    }
 }
 
 impl<'a> Worksheet<'a> {
@@ -108,6 +146,7 @@ impl<'a> Worksheet<'a> {
     /// An autofilter is a way of adding drop down lists to the headers of a 2D range of worksheet data.
     /// This allows users to filter the data based on simple criteria so that some data is shown and some is hidden.
     ///
+    /// This replaces the autofilter of [`Worksheet::add_filtered_header`], which is then no longer extended.
     pub fn autofilter(
         &mut self,
         first_row: WorksheetRow,
@@ -123,12 +162,55 @@ impl<'a> Worksheet<'a> {
                 last_row,
                 last_col,
             );
-            if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
-                Ok(())
-            } else {
-                Err(XlsxError::new(result))
+            if result != libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                return Err(XlsxError::new(result));
+            }
+        }
+        self._workbook
+            .worksheet_state(self.worksheet)
+            .filtered_header = None;
+        Ok(())
+    }
+
+    /// Write a header row starting in column A and add an autofilter to it.
+    ///
+    /// The autofilter covers the header columns and every row written below the header, up to the last row
+    /// written when the workbook is closed, so the data can be written after this call. Filter rules such as
+    /// [`Worksheet::filter_column`] can be added to it before or after the data.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_add_filtered_header-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.add_filtered_header(0, &["Region", "Product", "Sales"], Format::new().set_bold())?;
+    /// worksheet.write_string(1, 0, "East", None)?;
+    /// worksheet.write_string(1, 1, "Apples", None)?;
+    /// worksheet.write_number(1, 2, 1200., None)?; // the autofilter is A1:C2
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn add_filtered_header(
+        &mut self,
+        row: WorksheetRow,
+        headers: &[&str],
+        format: &Format,
+    ) -> Result<(), XlsxError> {
+        let last_col = match headers.len().checked_sub(1).map(WorksheetCol::try_from) {
+            Some(Ok(last_col)) => last_col,
+            _ => {
+                return Err(XlsxError::new(
+                    libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+                ))
             }
+        };
+        for (col, header) in (0..=last_col).zip(headers.iter()) {
+            self.write_string(row, col, header, Some(format))?;
         }
+        self.autofilter(row, 0, row, last_col)?;
+        self._workbook
+            .worksheet_state(self.worksheet)
+            .filtered_header = Some((row, last_col));
+        Ok(())
     }
 
     /// This function can be used to filter columns in a autofilter range based on single rule conditions.
@@ -177,6 +259,44 @@ impl<'a> Worksheet<'a> {
         Ok(())
     }
 
+    /// Filter a column with [`Worksheet::filter_column`] and hide the rows that don't match the rule, so the file
+    /// opens with the filtered view. `data` holds the values of the column, starting at the row below the autofilter
+    /// header.
+    /// ```rust
+    /// use xlsxwriter::prelude::*;
+    /// # use xlsxwriter::worksheet::filter::*;
+    ///
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_filter_column_and_hide.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let data: Vec<CellValue> = vec!["East".into(), "West".into(), "East".into()];
+    /// worksheet.write_string(0, 0, "Region", None)?;
+    /// worksheet.write_column(1, 0, &data, None)?;
+    /// worksheet.autofilter(0, 0, 3, 0)?;
+    /// worksheet.filter_column_and_hide(0, &FilterRule::new(FilterCriteria::EqualTo, "East"), &data)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    ///
+    /// Rows with values that can't be evaluated without Excel, such as formulas and dates, are left visible.
+    pub fn filter_column_and_hide(
+        &mut self,
+        col: crate::WorksheetCol,
+        rule: &FilterRule,
+        data: &[CellValue],
+    ) -> Result<(), XlsxError> {
+        self.filter_column(col, rule)?;
+        let first_row = unsafe { (*self.worksheet).autofilter.first_row } + 1;
+        for (row, value) in (first_row..).zip(data.iter()) {
+            if rule.matches(value) == Some(false) {
+                let mut options = self.row_settings(row).options;
+                options.hidden = true;
+                self.set_row_outline(row, &options)?;
+            }
+        }
+        Ok(())
+    }
+
     /// This function can be used to filter columns in a autofilter range based on two rule conditions.
     ///
     /// ```rust
@@ -301,6 +421,127 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_add_filtered_header() -> Result<(), XlsxError> {
+        let workbook = Workbook::new("test-worksheet_add_filtered_header.xlsx")?;
+        let mut worksheet = workbook.add_worksheet(None)?;
+        let mut bold = Format::new();
+        bold.set_bold();
+        worksheet.add_filtered_header(2, &["Region", "Product", "Sales", "Margin"], &bold)?;
+        unsafe {
+            let autofilter = (*worksheet.worksheet).autofilter;
+            assert_eq!(
+                (
+                    autofilter.first_row,
+                    autofilter.first_col,
+                    autofilter.last_row,
+                    autofilter.last_col
+                ),
+                (2, 0, 2, 3)
+            );
+        }
+        for row in 3..13 {
+            worksheet.write_string(row, 0, "East", None)?;
+            worksheet.write_number(row, 2, row.into(), None)?;
+        }
+        workbook.write_deferred_settings()?;
+        unsafe {
+            let autofilter = (*worksheet.worksheet).autofilter;
+            assert_eq!(
+                (
+                    autofilter.first_row,
+                    autofilter.first_col,
+                    autofilter.last_row,
+                    autofilter.last_col
+                ),
+                (2, 0, 12, 3)
+            );
+        }
+        assert!(worksheet.add_filtered_header(0, &[], &bold).is_err());
+
+        // Filter rules are kept when the autofilter is extended
+        let mut filtered = workbook.add_worksheet(None)?;
+        filtered.add_filtered_header(0, &["Region", "Sales"], &bold)?;
+        filtered.filter_column(1, &FilterRule::new(FilterCriteria::GreaterThan, 5.0))?;
+        for row in 1..=10 {
+            filtered.write_number(row, 1, row.into(), None)?;
+        }
+        workbook.write_deferred_settings()?;
+        unsafe {
+            let autofilter = (*filtered.worksheet).autofilter;
+            assert_eq!((autofilter.first_row, autofilter.last_row), (0, 10));
+            let rule = *(*filtered.worksheet).filter_rules.add(1);
+            assert!(!rule.is_null());
+            assert_eq!((*rule).value1, 5.0);
+        }
+
+        // Rows hidden by filter_column_and_hide keep their active filter
+        let mut hidden = workbook.add_worksheet(None)?;
+        hidden.add_filtered_header(0, &["Region", "Sales"], &bold)?;
+        let data: Vec<CellValue> = vec![3.into(), 8.into(), 1.into()];
+        hidden.write_column(1, 1, &data, None)?;
+        hidden.filter_column_and_hide(
+            1,
+            &FilterRule::new(FilterCriteria::GreaterThan, 5.0),
+            &data,
+        )?;
+        workbook.write_deferred_settings()?;
+        let hidden_rows: Vec<bool> = (1..=3)
+            .map(|row| hidden.row_settings(row).options.hidden)
+            .collect();
+        assert_eq!(hidden_rows, vec![true, false, true]);
+        unsafe {
+            let autofilter = (*hidden.worksheet).autofilter;
+            assert_eq!((autofilter.first_row, autofilter.last_row), (0, 3));
+            assert!(!(*(*hidden.worksheet).filter_rules.add(1)).is_null());
+        }
+
+        // An autofilter added later replaces the header autofilter
+        let mut replaced = workbook.add_worksheet(None)?;
+        replaced.add_filtered_header(0, &["Region", "Sales"], &bold)?;
+        replaced.autofilter(0, 0, 3, 1)?;
+        for row in 1..=10 {
+            replaced.write_number(row, 1, row.into(), None)?;
+        }
+        workbook.write_deferred_settings()?;
+        unsafe {
+            assert_eq!((*replaced.worksheet).autofilter.last_row, 3);
+        }
+        workbook.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_column_and_hide() -> Result<(), XlsxError> {
+        let workbook = Workbook::new("test-worksheet_filter_column_and_hide.xlsx")?;
+        let mut worksheet = workbook.add_worksheet(None)?;
+        let data: Vec<CellValue> = vec![
+            4.into(),
+            12.into(),
+            "10".into(),
+            CellValue::Blank,
+            CellValue::Formula("=A1*2".to_string()),
+            30.into(),
+        ];
+        worksheet.write_string(0, 0, "Value", None)?;
+        worksheet.write_column(1, 0, &data, None)?;
+        worksheet.autofilter(0, 0, 6, 0)?;
+        let rule = FilterRule::new(FilterCriteria::GreaterThan, 10.0);
+        worksheet.filter_column_and_hide(0, &rule, &data)?;
+        let hidden: Vec<bool> = (1..=6)
+            .map(|row| worksheet.row_settings(row).options.hidden)
+            .collect();
+        assert_eq!(hidden, vec![true, false, true, true, false, false]);
+        unsafe {
+            let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, 1);
+            assert!(!row.is_null());
+            assert_eq!((*row).hidden, 1);
+        }
+        assert!(worksheet.filter_column_and_hide(1, &rule, &data).is_err());
+        workbook.close()?;
+        Ok(())
+    }
+
     #[test]
     fn test_autofilter() -> Result<(), XlsxError> {
         let workbook = Workbook::new("test-worksheet_autofilter.xlsx")?;
@@ -0,0 +1,216 @@
+/// Pixel size and resolution of an image file, read the same way libxlsxwriter reads them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ImageSize {
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    pub(crate) x_dpi: f64,
+    pub(crate) y_dpi: f64,
+}
+
+const DEFAULT_DPI: f64 = 96.0;
+
+fn read_u16_be(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u32::from(u16::from_be_bytes([bytes[0], bytes[1]])))
+}
+
+fn read_u32_be(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn read_u16_le(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u32::from(u16::from_le_bytes([bytes[0], bytes[1]])))
+}
+
+fn read_i32_le(data: &[u8], offset: usize) -> Option<i32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+impl ImageSize {
+    /// Read the size of a PNG, JPEG, BMP or GIF image. Returns `None` for other or broken files.
+    pub(crate) fn from_bytes(data: &[u8]) -> Option<ImageSize> {
+        if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Self::from_png(data)
+        } else if data.starts_with(&[0xFF, 0xD8]) {
+            Self::from_jpeg(data)
+        } else if data.starts_with(b"BM") {
+            Some(ImageSize {
+                width: read_i32_le(data, 18)?.unsigned_abs(),
+                height: read_i32_le(data, 22)?.unsigned_abs(),
+                x_dpi: DEFAULT_DPI,
+                y_dpi: DEFAULT_DPI,
+            })
+        } else if data.starts_with(b"GIF8") {
+            Some(ImageSize {
+                width: read_u16_le(data, 6)?,
+                height: read_u16_le(data, 8)?,
+                x_dpi: DEFAULT_DPI,
+                y_dpi: DEFAULT_DPI,
+            })
+        } else {
+            None
+        }
+    }
+
+    fn from_png(data: &[u8]) -> Option<ImageSize> {
+        let mut size = ImageSize {
+            width: read_u32_be(data, 16)?,
+            height: read_u32_be(data, 20)?,
+            x_dpi: DEFAULT_DPI,
+            y_dpi: DEFAULT_DPI,
+        };
+
+        let mut offset: usize = 8;
+        while offset < data.len() {
+            let (length, chunk_type) =
+                match (read_u32_be(data, offset), data.get(offset + 4..offset + 8)) {
+                    (Some(length), Some(chunk_type)) => (length, chunk_type),
+                    _ => break,
+                };
+            match chunk_type {
+                b"pHYs" => {
+                    let x_ppu = read_u32_be(data, offset + 8)?;
+                    let y_ppu = read_u32_be(data, offset + 12)?;
+                    // Only pixels per meter are convertible to DPI
+                    if data.get(offset + 16) == Some(&1) && x_ppu > 0 && y_ppu > 0 {
+                        size.x_dpi = f64::from(x_ppu) * 0.0254;
+                        size.y_dpi = f64::from(y_ppu) * 0.0254;
+                    }
+                }
+                b"IEND" => break,
+                _ => {}
+            }
+            // A chunk that doesn't fit in the data is a broken file
+            offset = offset.checked_add((length as usize).checked_add(12)?)?;
+            if offset > data.len() {
+                return None;
+            }
+        }
+        Some(size)
+    }
+
+    fn from_jpeg(data: &[u8]) -> Option<ImageSize> {
+        let mut dpi = (DEFAULT_DPI, DEFAULT_DPI);
+        let mut offset = 2;
+        while data.len().saturating_sub(offset) >= 4 {
+            if data[offset] != 0xFF {
+                return None;
+            }
+            let marker = data[offset + 1];
+            let length = read_u16_be(data, offset + 2)? as usize;
+            let segment = offset + 4;
+            match marker {
+                0xE0 if data.get(segment..segment + 5) == Some(&b"JFIF\0"[..]) => {
+                    let units = *data.get(segment + 7)?;
+                    let x_density = f64::from(read_u16_be(data, segment + 8)?);
+                    let y_density = f64::from(read_u16_be(data, segment + 10)?);
+                    if x_density > 0.0 && y_density > 0.0 {
+                        match units {
+                            1 => dpi = (x_density, y_density),
+                            2 => dpi = (x_density * 2.54, y_density * 2.54),
+                            _ => {}
+                        }
+                    }
+                }
+                // Start of frame markers, excluding DHT, JPG and DAC
+                0xC0..=0xCF if marker != 0xC4 && marker != 0xC8 && marker != 0xCC => {
+                    return Some(ImageSize {
+                        width: read_u16_be(data, segment + 3)?,
+                        height: read_u16_be(data, segment + 1)?,
+                        x_dpi: dpi.0,
+                        y_dpi: dpi.1,
+                    });
+                }
+                _ => {}
+            }
+            offset = offset.saturating_add(length + 2);
+        }
+        None
+    }
+
+    /// Width of the image at 100% scale in Excel, in pixels.
+    pub(crate) fn display_width(&self) -> f64 {
+        f64::from(self.width) * DEFAULT_DPI / self.x_dpi
+    }
+
+    /// Height of the image at 100% scale in Excel, in pixels.
+    pub(crate) fn display_height(&self) -> f64 {
+        f64::from(self.height) * DEFAULT_DPI / self.y_dpi
+    }
+
+    /// Scale that fits the image into a box of `width` x `height` pixels, keeping its aspect ratio.
+    pub(crate) fn fit_scale(&self, width: u32, height: u32) -> f64 {
+        let x_scale = f64::from(width) / self.display_width();
+        let y_scale = f64::from(height) / self.display_height();
+        x_scale.min(y_scale)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_png_size() {
+        let size = ImageSize::from_bytes(include_bytes!("../../../images/simple1.png")).unwrap();
+        assert_eq!(size.width, 425);
+        assert_eq!(size.height, 165);
+        assert!((size.x_dpi - 95.9866).abs() < 0.001);
+        assert!((size.display_width() - 425.0 * 96.0 / 95.9866).abs() < 0.001);
+
+        // A chunk length near u32::MAX must not overflow the offset on 32-bit targets, and is past the end of the data
+        let mut png = b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR".to_vec();
+        png.extend_from_slice(&[0, 0, 0, 1, 0, 0, 0, 1, 8, 6, 0, 0, 0, 0, 0, 0, 0]);
+        png.extend_from_slice(b"\xff\xff\xff\xfftEXt");
+        assert!(ImageSize::from_bytes(&png).is_none());
+    }
+
+    #[test]
+    fn test_fit_scale() {
+        let size = ImageSize {
+            width: 400,
+            height: 100,
+            x_dpi: DEFAULT_DPI,
+            y_dpi: DEFAULT_DPI,
+        };
+        assert_eq!(size.fit_scale(200, 200), 0.5);
+        assert_eq!(size.fit_scale(800, 50), 0.5);
+        assert_eq!(size.fit_scale(800, 400), 2.0);
+
+        let size = ImageSize {
+            x_dpi: 192.0,
+            y_dpi: 192.0,
+            ..size
+        };
+        assert_eq!(size.fit_scale(200, 200), 1.0);
+    }
+
+    #[test]
+    fn test_other_sizes() {
+        let mut gif = b"GIF89a".to_vec();
+        gif.extend_from_slice(&[0x20, 0x01, 0x40, 0x00]);
+        assert_eq!(
+            ImageSize::from_bytes(&gif),
+            Some(ImageSize {
+                width: 288,
+                height: 64,
+                x_dpi: DEFAULT_DPI,
+                y_dpi: DEFAULT_DPI
+            })
+        );
+
+        let mut jpeg = vec![0xFF, 0xD8];
+        jpeg.extend_from_slice(&[0xFF, 0xE0, 0x00, 0x10]);
+        jpeg.extend_from_slice(b"JFIF\0\x01\x01\x01\x00\x48\x00\x48\x00\x00");
+        jpeg.extend_from_slice(&[0xFF, 0xC0, 0x00, 0x11, 0x08, 0x00, 0x20, 0x00, 0x40]);
+        let size = ImageSize::from_bytes(&jpeg).unwrap();
+        assert_eq!((size.width, size.height), (64, 32));
+        assert_eq!((size.x_dpi, size.y_dpi), (72.0, 72.0));
+
+        assert_eq!(ImageSize::from_bytes(b"not an image"), None);
+        assert_eq!(ImageSize::from_bytes(b"\x89PNG\r\n\x1a\n"), None);
+    }
+}
@@ -0,0 +1,249 @@
+use super::{Worksheet, WorksheetCol, WorksheetRow};
+use crate::{Format, XlsxError};
+
+/// A run of text with the same style.
+#[derive(Debug, Clone, PartialEq)]
+struct Fragment {
+    text: String,
+    bold: bool,
+    italic: bool,
+}
+
+/// Split text with `**bold**` and `*italic*` markers into fragments. A marker without a matching closing marker is
+/// kept as text, and `\*` is a literal asterisk.
+fn parse_markdown(text: &str) -> Vec<Fragment> {
+    let mut fragments: Vec<Fragment> = Vec::new();
+    let mut current = String::new();
+    let (mut bold, mut italic) = (false, false);
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        let toggle = if let Some(after) = rest.strip_prefix("**") {
+            if bold || after.contains("**") {
+                bold = !bold;
+                Some(after)
+            } else {
+                None
+            }
+        } else if let Some(after) = rest.strip_prefix('*') {
+            if italic || after.replace("**", "").contains('*') {
+                italic = !italic;
+                Some(after)
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        match toggle {
+            Some(after) => {
+                // The style of the text before the marker is the style before the toggle.
+                let (text_bold, text_italic) = if rest.starts_with("**") {
+                    (!bold, italic)
+                } else {
+                    (bold, !italic)
+                };
+                push_fragment(&mut fragments, &mut current, text_bold, text_italic);
+                rest = after;
+            }
+            None => {
+                if let Some(after) = rest.strip_prefix("\\*") {
+                    current.push('*');
+                    rest = after;
+                } else {
+                    current.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+    }
+    push_fragment(&mut fragments, &mut current, bold, italic);
+    fragments
+}
+
+/// Add the collected text as a fragment, merging it with the previous fragment if the style is the same.
+fn push_fragment(fragments: &mut Vec<Fragment>, current: &mut String, bold: bool, italic: bool) {
+    if current.is_empty() {
+        return;
+    }
+    match fragments.last_mut() {
+        Some(last) if last.bold == bold && last.italic == italic => last.text.push_str(current),
+        _ => fragments.push(Fragment {
+            text: current.clone(),
+            bold,
+            italic,
+        }),
+    }
+    current.clear();
+}
+
+impl<'a> Worksheet<'a> {
+    /// Write text with a tiny subset of markdown as a rich string: `**bold**` and `*italic*`, which can be nested as
+    /// in `**bold and *italic***`. A marker without a matching closing marker is written as it is, and `\*` writes
+    /// a literal asterisk. No other markdown syntax is supported.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_markdown_cell-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_markdown_cell(0, 0, "**bold** and *italic*", None)?;
+    /// worksheet.write_markdown_cell(1, 0, "Total: **42**", Some(&Format::new().set_font_size(14.)))?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// `base_format` is the format of the cell and the font of every fragment, with bold or italic added to it.
+    /// Text without any markers, or styled as a whole such as `**Total**`, is written with [`Worksheet::write_string`].
+    pub fn write_markdown_cell(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        text: &str,
+        base_format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        let fragments = parse_markdown(text);
+        if fragments.iter().all(|x| !x.bold && !x.italic) {
+            let text: String = fragments.into_iter().map(|x| x.text).collect();
+            return self.write_string(row, col, &text, base_format);
+        }
+
+        let formats: Vec<Option<Format>> = fragments
+            .iter()
+            .map(|fragment| {
+                if !fragment.bold && !fragment.italic {
+                    return base_format.cloned();
+                }
+                let mut format = base_format.cloned().unwrap_or_default();
+                if fragment.bold {
+                    format.set_bold();
+                }
+                if fragment.italic {
+                    format.set_italic();
+                }
+                Some(format)
+            })
+            .collect();
+        // libxlsxwriter needs at least two fragments in a rich string
+        if fragments.len() == 1 {
+            return self.write_string(row, col, &fragments[0].text, formats[0].as_ref());
+        }
+        let rich_text: Vec<(&str, Option<&Format>)> = fragments
+            .iter()
+            .zip(formats.iter())
+            .map(|(fragment, format)| (fragment.text.as_str(), format.as_ref()))
+            .collect();
+        self.write_rich_string(row, col, &rich_text, base_format)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fragment(text: &str, bold: bool, italic: bool) -> Fragment {
+        Fragment {
+            text: text.to_string(),
+            bold,
+            italic,
+        }
+    }
+
+    #[test]
+    fn test_parse_bold() {
+        assert_eq!(
+            parse_markdown("a **bold** word"),
+            vec![
+                fragment("a ", false, false),
+                fragment("bold", true, false),
+                fragment(" word", false, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_italic() {
+        assert_eq!(
+            parse_markdown("*italic* text"),
+            vec![
+                fragment("italic", false, true),
+                fragment(" text", false, false)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_mixed() {
+        assert_eq!(
+            parse_markdown("**bold** and *italic*"),
+            vec![
+                fragment("bold", true, false),
+                fragment(" and ", false, false),
+                fragment("italic", false, true),
+            ]
+        );
+        assert_eq!(
+            parse_markdown("**bold and *both***"),
+            vec![
+                fragment("bold and ", true, false),
+                fragment("both", true, true)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_literal_markers() {
+        assert_eq!(
+            parse_markdown("2 * 3 = 6"),
+            vec![fragment("2 * 3 = 6", false, false)]
+        );
+        assert_eq!(
+            parse_markdown("\\*not italic\\*"),
+            vec![fragment("*not italic*", false, false)]
+        );
+        assert_eq!(
+            parse_markdown("**unclosed"),
+            vec![fragment("**unclosed", false, false)]
+        );
+        assert_eq!(parse_markdown(""), vec![]);
+    }
+
+    #[test]
+    fn test_write_markdown_cell() -> Result<(), XlsxError> {
+        let workbook = crate::Workbook::new("test-worksheet_write_markdown_cell.xlsx")?;
+        let mut worksheet = workbook.add_worksheet(None)?;
+        worksheet.write_markdown_cell(0, 0, "**bold** and *italic*", None)?;
+        worksheet.write_markdown_cell(1, 0, "plain text", None)?;
+        worksheet.write_markdown_cell(2, 0, "****", None)?;
+        let mut large = Format::new();
+        large.set_font_size(14.);
+        worksheet.write_markdown_cell(3, 0, "**Total**", None)?;
+        worksheet.write_markdown_cell(4, 0, "*note*", Some(&large))?;
+
+        let cell = |row| unsafe {
+            let row = libxlsxwriter_sys::lxw_worksheet_find_row(worksheet.worksheet, row);
+            libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(row, 0)
+        };
+        let mut bold = Format::new();
+        bold.set_bold();
+        let mut large_italic = large.clone();
+        large_italic.set_italic();
+        unsafe {
+            assert_eq!(
+                u32::from((*cell(0)).type_),
+                libxlsxwriter_sys::cell_types_STRING_CELL
+            );
+            assert!((*cell(0)).format.is_null());
+            assert_eq!(
+                u32::from((*cell(3)).type_),
+                libxlsxwriter_sys::cell_types_STRING_CELL
+            );
+            assert_eq!((*cell(3)).format, workbook.get_internal_format(&bold)?);
+            assert_eq!(
+                (*cell(4)).format,
+                workbook.get_internal_format(&large_italic)?
+            );
+        }
+        workbook.close()?;
+        Ok(())
+    }
+}
@@ -1,16 +1,36 @@
+mod cell_value;
 pub mod conditional_format;
+mod cursor;
 mod datetime;
 pub mod filter;
+mod image;
+mod markdown;
+mod page_setup;
+mod report;
+mod state;
+mod stats;
 pub mod table;
+mod template;
 pub mod validation;
 
 use crate::CStringHelper;
 
-use super::{convert_bool, Chart, Format, FormatColor, Workbook, XlsxError};
+use super::{
+    convert_bool, Chart, Currency, Format, FormatAlignment, FormatColor, FormatVerticalAlignment,
+    Workbook, XlsxError,
+};
 use std::ffi::CString;
 use std::os::raw::c_char;
 
+pub use cell_value::*;
+pub use cursor::*;
 pub use datetime::*;
+pub(crate) use image::ImageSize;
+pub use page_setup::*;
+pub use report::*;
+pub(crate) use state::*;
+pub use stats::*;
+pub use template::*;
 
 /// Integer data type to represent a column value. Equivalent to `u16`.
 ///
@@ -33,6 +53,8 @@ pub struct DateTime {
 }
 
 /// Options for modifying images inserted via [`Worksheet.insert_image_opt`](struct.Worksheet.html#method.insert_image_opt).
+///
+/// The default options insert the image at its original size at the top-left corner of the cell.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct ImageOptions {
     /// Offset from the left of the cell in pixels.
@@ -43,21 +65,39 @@ pub struct ImageOptions {
     pub x_scale: f64,
     /// Y scale of the image as a decimal.
     pub y_scale: f64,
+    /// Alternative text of the image, read out by screen readers. Excel uses the file name if it is `None`.
+    pub description: Option<String>,
+}
+
+impl Default for ImageOptions {
+    fn default() -> Self {
+        ImageOptions {
+            x_offset: 0,
+            y_offset: 0,
+            x_scale: 1.0,
+            y_scale: 1.0,
+            description: None,
+        }
+    }
 }
 
-impl From<&ImageOptions> for libxlsxwriter_sys::lxw_image_options {
-    fn from(options: &ImageOptions) -> Self {
-        libxlsxwriter_sys::lxw_image_options {
-            x_offset: options.x_offset,
-            y_offset: options.y_offset,
-            x_scale: options.x_scale,
-            y_scale: options.y_scale,
-            description: std::ptr::null_mut(),
+impl ImageOptions {
+    /// Convert the options to the libxlsxwriter struct, with the strings kept in `c_string_helper`.
+    pub(crate) fn to_c_struct(
+        &self,
+        c_string_helper: &mut CStringHelper,
+    ) -> Result<libxlsxwriter_sys::lxw_image_options, XlsxError> {
+        Ok(libxlsxwriter_sys::lxw_image_options {
+            x_offset: self.x_offset,
+            y_offset: self.y_offset,
+            x_scale: self.x_scale,
+            y_scale: self.y_scale,
+            description: c_string_helper.add_opt(self.description.as_deref())? as *mut c_char,
             url: std::ptr::null_mut(),
             tip: std::ptr::null_mut(),
             object_position: 0,
             decorative: 0,
-        }
+        })
     }
 }
 
@@ -145,6 +185,62 @@ impl GridLines {
     }
 }
 
+/// Excel warnings that can be turned off for a range of cells with [`Worksheet::ignore_errors`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IgnoreError {
+    /// A number stored as text, or a text cell that looks like a number.
+    NumberStoredAsText,
+    /// A formula that evaluates to an error.
+    EvalError,
+    /// A formula that differs from the formulas around it.
+    FormulaDiffers,
+    /// A formula that omits some of the adjacent cells.
+    FormulaRange,
+    /// An unlocked cell containing a formula.
+    FormulaUnlocked,
+    /// A formula that refers to empty cells.
+    EmptyCellReference,
+    /// A value that doesn't match the list data validation of the cell.
+    ListDataValidation,
+    /// A formula that is inconsistent with the calculated column of a table.
+    CalculatedColumn,
+    /// A text date with a two digit year.
+    TwoDigitTextYear,
+}
+
+impl IgnoreError {
+    pub(crate) fn value(self) -> u8 {
+        let value = match self {
+            IgnoreError::NumberStoredAsText => {
+                libxlsxwriter_sys::lxw_ignore_errors_LXW_IGNORE_NUMBER_STORED_AS_TEXT
+            }
+            IgnoreError::EvalError => libxlsxwriter_sys::lxw_ignore_errors_LXW_IGNORE_EVAL_ERROR,
+            IgnoreError::FormulaDiffers => {
+                libxlsxwriter_sys::lxw_ignore_errors_LXW_IGNORE_FORMULA_DIFFERS
+            }
+            IgnoreError::FormulaRange => {
+                libxlsxwriter_sys::lxw_ignore_errors_LXW_IGNORE_FORMULA_RANGE
+            }
+            IgnoreError::FormulaUnlocked => {
+                libxlsxwriter_sys::lxw_ignore_errors_LXW_IGNORE_FORMULA_UNLOCKED
+            }
+            IgnoreError::EmptyCellReference => {
+                libxlsxwriter_sys::lxw_ignore_errors_LXW_IGNORE_EMPTY_CELL_REFERENCE
+            }
+            IgnoreError::ListDataValidation => {
+                libxlsxwriter_sys::lxw_ignore_errors_LXW_IGNORE_LIST_DATA_VALIDATION
+            }
+            IgnoreError::CalculatedColumn => {
+                libxlsxwriter_sys::lxw_ignore_errors_LXW_IGNORE_CALCULATED_COLUMN
+            }
+            IgnoreError::TwoDigitTextYear => {
+                libxlsxwriter_sys::lxw_ignore_errors_LXW_IGNORE_TWO_DIGIT_TEXT_YEAR
+            }
+        };
+        value as u8
+    }
+}
+
 /// Sheet protection
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 pub struct Protection {
@@ -275,7 +371,23 @@ impl CommentDisplayType {
 }
 
 /// Options for modifying comments inserted via `write_comment_opt()`
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+///
+/// Options that are not set use the Excel defaults.
+/// ```rust
+/// # use xlsxwriter::prelude::*;
+/// # fn main() -> Result<(), XlsxError> {
+/// # let workbook = Workbook::new("test-worksheet-comment_options-1.xlsx")?;
+/// # let mut worksheet = workbook.add_worksheet(None)?;
+/// let mut options = CommentOptions::new();
+/// options
+///     .set_author("Reviewer")
+///     .set_visible(CommentDisplayType::Visible)
+///     .set_start_cell(1, 3);
+/// worksheet.write_comment_opt(1, 1, "The box starts at D2, two columns to the right", &options)?;
+/// # workbook.close()
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
 pub struct CommentOptions {
     visible: CommentDisplayType,
     author: Option<String>,
@@ -283,7 +395,7 @@ pub struct CommentOptions {
     height: Option<u16>,
     x_scale: Option<f64>,
     y_scale: Option<f64>,
-    color: FormatColor,
+    color: Option<FormatColor>,
     font_name: Option<String>,
     font_size: Option<f64>,
     font_family: Option<u8>,
@@ -291,9 +403,129 @@ pub struct CommentOptions {
     start_col: WorksheetCol,
     x_offset: i32,
     y_offset: i32,
+    auto_size: bool,
 }
 
 impl CommentOptions {
+    #[must_use]
+    pub fn new() -> Self {
+        CommentOptions::default()
+    }
+
+    /// Show or hide the comment when the worksheet is opened. By default comments are only shown when the mouse is over the cell.
+    pub fn set_visible(&mut self, visible: CommentDisplayType) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the author of the comment, shown in the status bar of Excel.
+    pub fn set_author(&mut self, author: &str) -> &mut Self {
+        self.author = Some(author.to_string());
+        self
+    }
+
+    /// Set the width of the comment box in pixels. The default is 128.
+    pub fn set_width(&mut self, width: u16) -> &mut Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the height of the comment box in pixels. The default is 74.
+    pub fn set_height(&mut self, height: u16) -> &mut Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Scale the width of the comment box, as an alternative to `set_width()`.
+    pub fn set_x_scale(&mut self, x_scale: f64) -> &mut Self {
+        self.x_scale = Some(x_scale);
+        self
+    }
+
+    /// Scale the height of the comment box, as an alternative to `set_height()`.
+    pub fn set_y_scale(&mut self, y_scale: f64) -> &mut Self {
+        self.y_scale = Some(y_scale);
+        self
+    }
+
+    /// Set the background color of the comment box. The default is a light yellow.
+    pub fn set_color(&mut self, color: FormatColor) -> &mut Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn set_font_name(&mut self, font_name: &str) -> &mut Self {
+        self.font_name = Some(font_name.to_string());
+        self
+    }
+
+    pub fn set_font_size(&mut self, font_size: f64) -> &mut Self {
+        self.font_size = Some(font_size);
+        self
+    }
+
+    pub fn set_font_family(&mut self, font_family: u8) -> &mut Self {
+        self.font_family = Some(font_family);
+        self
+    }
+
+    /// Set the cell where the top-left corner of the comment box is placed. The arrow of the box still points to the
+    /// commented cell, so this moves the box away from the data. By default the box is placed one column to the right of the cell.
+    pub fn set_start_cell(&mut self, row: WorksheetRow, col: WorksheetCol) -> &mut Self {
+        self.start_row = row;
+        self.start_col = col;
+        self
+    }
+
+    /// Set the offset of the comment box from the start cell in pixels.
+    pub fn set_offset(&mut self, x_offset: i32, y_offset: i32) -> &mut Self {
+        self.x_offset = x_offset;
+        self.y_offset = y_offset;
+        self
+    }
+
+    /// Size the comment box to fit its text, unless the width or height is set with `set_width()` or `set_height()`.
+    /// The box is never smaller than the default size, and long lines are wrapped at a width of 320 pixels.
+    ///
+    /// libxlsxwriter can't measure text, so the size is estimated from the number of characters and lines with an
+    /// average character width, and may be a little too large or too small for proportional fonts.
+    pub fn set_auto_size(&mut self) -> &mut Self {
+        self.auto_size = true;
+        self
+    }
+
+    /// Estimate the size in pixels of a comment box that fits `text`, at the width set with
+    /// [`CommentOptions::set_width`] if there is one.
+    fn fitted_size(&self, text: &str) -> (u16, u16) {
+        let font_size = self
+            .font_size
+            .filter(|x| *x > 0.0)
+            .unwrap_or(COMMENT_FONT_SIZE);
+        let char_width = font_size * 0.75;
+        let line_height = font_size * 5.0 / 3.0;
+        let longest = text
+            .split('\n')
+            .map(|x| x.chars().count())
+            .max()
+            .unwrap_or(0);
+        // A width set with `set_width` is kept, and the height fits the text wrapped at that width
+        let width = match self.width {
+            Some(width) => f64::from(width),
+            None => (longest as f64 * char_width + COMMENT_PADDING)
+                .max(f64::from(COMMENT_DEFAULT_WIDTH))
+                .min(f64::from(COMMENT_MAX_AUTO_WIDTH)),
+        };
+        let chars_per_line = (((width - COMMENT_PADDING) / char_width).floor() as usize).max(1);
+        let lines: usize = text
+            .split('\n')
+            .map(|line| ((line.chars().count() + chars_per_line - 1) / chars_per_line).max(1))
+            .sum();
+        let height = (lines as f64 * line_height + COMMENT_PADDING)
+            .max(f64::from(COMMENT_DEFAULT_HEIGHT))
+            .min(f64::from(u16::MAX));
+        (width.ceil() as u16, height.ceil() as u16)
+    }
+
     pub(crate) fn to_internal(
         &self,
         workbook: &Workbook,
@@ -305,7 +537,7 @@ impl CommentOptions {
             height: self.height.unwrap_or_default(),
             x_scale: self.x_scale.unwrap_or_default(),
             y_scale: self.y_scale.unwrap_or_default(),
-            color: self.color.value(),
+            color: self.color.map_or(0, FormatColor::value),
             font_name: workbook.register_option_str(self.font_name.as_deref())? as *mut c_char,
             font_size: self.font_size.unwrap_or_default(),
             font_family: self.font_family.unwrap_or_default(),
@@ -325,6 +557,177 @@ pub const LXW_DEF_ROW_HEIGHT_PIXELS: u32 = 20;
 pub const LXW_DEF_COL_WIDTH: f64 = 8.43;
 /// Default Excel column width in pixels.
 pub const LXW_DEF_COL_WIDTH_PIXELS: u32 = 64;
+/// Maximum number of characters in a cell string.
+pub(crate) const STRING_MAX_LENGTH: usize = 32767;
+/// Maximum number of hyperlinks in a worksheet.
+pub(crate) const MAX_HYPERLINKS: usize = 65530;
+/// Maximum number of characters in a hyperlink tooltip.
+const MAX_TOOLTIP_LENGTH: usize = 255;
+/// Number of rows in a worksheet.
+pub(crate) const ROW_COUNT: u32 = 1_048_576;
+/// Number of columns in a worksheet.
+pub(crate) const COL_COUNT: u32 = 16_384;
+/// Default width of an inserted chart in pixels.
+const CHART_DEFAULT_WIDTH_PIXELS: u32 = 480;
+/// Default height of an inserted chart in pixels.
+const CHART_DEFAULT_HEIGHT_PIXELS: u32 = 288;
+/// Default width of a comment box in pixels.
+const COMMENT_DEFAULT_WIDTH: u16 = 128;
+/// Default height of a comment box in pixels.
+const COMMENT_DEFAULT_HEIGHT: u16 = 74;
+/// Width at which the text of an auto-sized comment box is wrapped, in pixels.
+const COMMENT_MAX_AUTO_WIDTH: u16 = 320;
+/// Default font size of comments in points.
+const COMMENT_FONT_SIZE: f64 = 8.0;
+/// Space between the text and the border of a comment box, in pixels.
+const COMMENT_PADDING: f64 = 10.0;
+/// Deepest outline level supported by Excel.
+const OUTLINE_LEVEL_MAX: u8 = 7;
+const ZOOM_MIN: u16 = 10;
+const ZOOM_MAX: u16 = 400;
+
+/// Check that a name is usable as a VBA codename.
+pub(crate) fn is_valid_vba_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(x) if x.is_ascii_alphabetic())
+        && chars.all(|x| x.is_ascii_alphanumeric() || x == '_')
+        && name.len() <= 31
+}
+
+/// Check if a string would be converted to a hyperlink by [`Worksheet::set_autoconvert_urls`].
+pub(crate) fn looks_like_url(text: &str) -> bool {
+    [
+        "http://",
+        "https://",
+        "ftp://",
+        "ftps://",
+        "mailto:",
+        "internal:",
+        "external:",
+    ]
+    .iter()
+    .any(|x| text.len() > x.len() && text.starts_with(x))
+        && !text.contains(char::is_whitespace)
+        && text.strip_prefix("internal:").map_or(true, is_link_target)
+}
+
+/// Check if the location of an `internal:` link can be a cell, a range, whole columns or rows such as `A:A` or
+/// `1:1`, or a defined name. Locations with a sheet name are not checked.
+fn is_link_target(location: &str) -> bool {
+    if location.contains('!') || crate::utility::range_to_rowcol(location).is_ok() {
+        return true;
+    }
+    // Some(true) for a column such as `$A`, Some(false) for a row such as `10`
+    let line = |part: &str| {
+        let part = part.strip_prefix('$').unwrap_or(part);
+        if part.is_empty() {
+            None
+        } else if part.chars().all(|c| c.is_ascii_alphabetic()) {
+            Some(true)
+        } else if part.chars().all(|c| c.is_ascii_digit()) {
+            Some(false)
+        } else {
+            None
+        }
+    };
+    if let Some((first, last)) = location.split_once(':') {
+        return line(first).is_some() && line(first) == line(last);
+    }
+    let mut chars = location.chars();
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' || c == '\\' => {
+            chars.all(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | '\\' | '?'))
+        }
+        _ => false,
+    }
+}
+
+/// Convert `\r\n` and `\r` line breaks to `\n`, which Excel uses in comments.
+pub(crate) fn comment_text(text: &str) -> std::borrow::Cow<'_, str> {
+    if text.contains('\r') {
+        text.replace("\r\n", "\n").replace('\r', "\n").into()
+    } else {
+        text.into()
+    }
+}
+
+/// Convert a hyperlink target into a URL understood by Excel. See [`Worksheet::write_link`].
+pub(crate) fn link_url(target: &str) -> std::borrow::Cow<'_, str> {
+    let target = target.trim();
+    let has_scheme = target.contains("://")
+        || ["mailto:", "internal:", "external:"]
+            .iter()
+            .any(|x| target.starts_with(x));
+    if has_scheme {
+        return target.into();
+    }
+
+    let is_email = match target.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+                && !domain.contains('@')
+                && !target.contains(char::is_whitespace)
+        }
+        None => false,
+    };
+    if is_email {
+        format!("mailto:{}", target).into()
+    } else if target.starts_with("www.") {
+        format!("http://{}", target).into()
+    } else {
+        target.into()
+    }
+}
+
+/// A rectangular range of cells, in zero indexed row and column numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct CellRange {
+    pub first_row: WorksheetRow,
+    pub first_col: WorksheetCol,
+    pub last_row: WorksheetRow,
+    pub last_col: WorksheetCol,
+}
+
+impl CellRange {
+    pub fn new(
+        first_row: WorksheetRow,
+        first_col: WorksheetCol,
+        last_row: WorksheetRow,
+        last_col: WorksheetCol,
+    ) -> Self {
+        CellRange {
+            first_row,
+            first_col,
+            last_row,
+            last_col,
+        }
+    }
+
+    /// Parse an A1 style range such as `"A1:C10"`. See [`crate::utility::range_to_rowcol`].
+    pub fn from_a1(range: &str) -> Result<CellRange, XlsxError> {
+        crate::utility::range_to_rowcol(range)
+    }
+
+    /// Return the range as an A1 style string such as `"A1:C10"`.
+    #[must_use]
+    pub fn to_a1(&self) -> String {
+        crate::utility::rowcol_to_range(
+            self.first_row,
+            self.first_col,
+            self.last_row,
+            self.last_col,
+        )
+    }
+}
+
+impl std::fmt::Display for CellRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_a1())
+    }
+}
 
 /// The Worksheet object represents an Excel worksheet. It handles operations such as writing data to cells or formatting worksheet layout.
 ///
@@ -353,10 +756,12 @@ impl<'a> Worksheet<'a> {
     /// # let workbook = Workbook::new("test-worksheet_write_comment-1.xlsx")?;
     /// # let mut worksheet = workbook.add_worksheet(None)?;
     /// worksheet.write_comment(0, 0, "This is some comment text")?;
-    /// worksheet.write_comment(1, 0, "This cell also has a comment")?;
+    /// worksheet.write_comment(1, 0, "First line\nSecond line\nThird line")?;
     /// # workbook.close()
     /// # }
     /// ```
+    /// Lines are separated with `\n`. Windows style `\r\n` line breaks are converted to `\n`, as Excel would show
+    /// the `\r`.
     pub fn write_comment(
         &mut self,
         row: WorksheetRow,
@@ -368,7 +773,9 @@ impl<'a> Worksheet<'a> {
                 self.worksheet,
                 row,
                 col,
-                CString::new(text)?.as_c_str().as_ptr(),
+                CString::new(comment_text(text).as_ref())?
+                    .as_c_str()
+                    .as_ptr(),
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
                 Ok(())
@@ -378,6 +785,7 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// Write a comment with options, see [`CommentOptions`]. Line breaks are handled as in [`Worksheet::write_comment`].
     pub fn write_comment_opt(
         &mut self,
         row: WorksheetRow,
@@ -385,14 +793,24 @@ impl<'a> Worksheet<'a> {
         text: &str,
         options: &CommentOptions,
     ) -> Result<(), XlsxError> {
-        let mut options = options.to_internal(self._workbook)?;
+        let text = comment_text(text);
+        let mut internal_options = options.to_internal(self._workbook)?;
+        if options.auto_size {
+            let (width, height) = options.fitted_size(&text);
+            if options.width.is_none() {
+                internal_options.width = width;
+            }
+            if options.height.is_none() {
+                internal_options.height = height;
+            }
+        }
         unsafe {
             let result = libxlsxwriter_sys::worksheet_write_comment_opt(
                 self.worksheet,
                 row,
                 col,
-                self._workbook.register_str(text)?,
-                &mut options,
+                self._workbook.register_str(&text)?,
+                &mut internal_options,
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
                 Ok(())
@@ -402,6 +820,24 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// Make all comments of the worksheet visible when the file is opened. Comments written with
+    /// [`CommentDisplayType::Hidden`] stay hidden.
+    ///
+    /// The setting is applied when the workbook is closed, so it can be called before or after the comments are written.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_show_comments-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.show_comments();
+    /// worksheet.write_comment(0, 0, "This comment is visible")?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn show_comments(&mut self) {
+        self._workbook.worksheet_state(self.worksheet).show_comments = true;
+    }
+
     /// This function writes numeric types to the cell specified by row and column:
     /// ```rust
     /// # use xlsxwriter::prelude::*;
@@ -455,6 +891,58 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function writes a number formatted as an amount of the given currency, with the currency symbol in the
+    /// usual place and the usual number of decimal places:
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet-write_currency-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_currency(0, 0, 1234.56, Currency::Usd)?; // $1,234.56
+    /// worksheet.write_currency(1, 0, 1234.56, Currency::Eur)?; // 1.234,56 €
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// Use [`Format::currency`] to combine a currency with other format properties.
+    pub fn write_currency(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        number: f64,
+        currency: Currency,
+    ) -> Result<(), XlsxError> {
+        self.write_number(row, col, number, Some(&Format::currency(currency)))
+    }
+
+    /// Write a number with the given number of decimal places followed by a unit, such as `3.5 kg`. The cell still
+    /// contains the number, so it can be used in formulas.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet-write_number_with_unit-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_number_with_unit(0, 0, 3.5, "kg", 1)?; // 3.5 kg
+    /// worksheet.write_number_with_unit(1, 0, 21.375, "°C", 2)?; // 21.38 °C
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// Use [`Format::number_with_unit`] to combine a unit with other format properties.
+    pub fn write_number_with_unit(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        number: f64,
+        unit: &str,
+        decimals: u8,
+    ) -> Result<(), XlsxError> {
+        self.write_number(
+            row,
+            col,
+            number,
+            Some(&Format::number_with_unit(decimals, unit)),
+        )
+    }
+
     /// This function writes a string to the cell specified by row and column:
     /// ```rust
     /// # use xlsxwriter::prelude::*;
@@ -491,6 +979,8 @@ impl<'a> Worksheet<'a> {
     /// # }
     /// ```
     /// ![Result Image](https://github.com/informationsea/xlsxwriter-rs/raw/master/images/test-worksheet-write_string-3.png)
+    ///
+    /// Strings that look like URLs are written as plain text, unless [`Worksheet::set_autoconvert_urls`] is turned on.
     pub fn write_string(
         &mut self,
         row: WorksheetRow,
@@ -498,6 +988,14 @@ impl<'a> Worksheet<'a> {
         text: &str,
         format: Option<&Format>,
     ) -> Result<(), XlsxError> {
+        let autoconvert_urls = self
+            ._workbook
+            .worksheet_state(self.worksheet)
+            .autoconvert_urls
+            .unwrap_or_else(|| self._workbook.string_url_conversion());
+        if autoconvert_urls && looks_like_url(text) {
+            return self.write_url(row, col, text, format);
+        }
         let mut c_string_helper = CStringHelper::new();
         unsafe {
             let result = libxlsxwriter_sys::worksheet_write_string(
@@ -515,6 +1013,118 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// Write a string of digits such as a zip code or an ID as text, so that leading zeros are kept: `"007"` is
+    /// shown as `007` instead of `7`.
+    ///
+    /// The cell gets the text number format `@` in addition to `format`, and Excel's "number stored as text" warning
+    /// is turned off for it with [`Worksheet::ignore_errors`].
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_number_as_text-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_number_as_text(0, 0, "007", None)?;
+    /// worksheet.write_number_as_text(1, 0, "01109", None)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn write_number_as_text(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        text: &str,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        let mut text_format = format.cloned().unwrap_or_default();
+        text_format.set_num_format("@");
+        self.write_string(row, col, text, Some(&text_format))?;
+        self.ignore_errors(
+            IgnoreError::NumberStoredAsText,
+            &crate::utility::rowcol_to_cell(row, col),
+        )
+    }
+
+    /// Turn off an Excel warning, shown as a green triangle in the corner of a cell, for a range such as `"A1:C10"`.
+    ///
+    /// The function can be called several times for the same warning; the ranges are collected and written when the
+    /// workbook is closed.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_ignore_errors-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_string(0, 0, "123", None)?;
+    /// worksheet.ignore_errors(IgnoreError::NumberStoredAsText, "A1")?;
+    /// worksheet.write_formula(1, 0, "=1/0", None)?;
+    /// worksheet.ignore_errors(IgnoreError::EvalError, "A2")?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn ignore_errors(&mut self, error: IgnoreError, range: &str) -> Result<(), XlsxError> {
+        let range = CellRange::from_a1(range)?;
+        self._workbook
+            .worksheet_state(self.worksheet)
+            .ignored_errors
+            .entry(error)
+            .or_default()
+            .push(range.to_a1());
+        Ok(())
+    }
+
+    /// Turn off several Excel warnings for the same range, see [`Worksheet::ignore_errors`].
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_ignore_errors_multi-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.ignore_errors_multi(
+    ///     &[IgnoreError::NumberStoredAsText, IgnoreError::FormulaDiffers],
+    ///     "A1:D100",
+    /// )?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn ignore_errors_multi(
+        &mut self,
+        errors: &[IgnoreError],
+        range: &str,
+    ) -> Result<(), XlsxError> {
+        let range = CellRange::from_a1(range)?.to_a1();
+        let mut state = self._workbook.worksheet_state(self.worksheet);
+        for error in errors {
+            state
+                .ignored_errors
+                .entry(*error)
+                .or_default()
+                .push(range.clone());
+        }
+        Ok(())
+    }
+
+    /// Turn on or off writing strings that look like URLs as hyperlinks in [`Worksheet::write_string`].
+    ///
+    /// libxlsxwriter writes every string as plain text, so this is off by default unless it was turned on for the whole
+    /// workbook with [`Workbook::set_string_url_conversion`]. This setting overrides the workbook setting for this
+    /// worksheet. When it is on, strings starting with
+    /// `http://`, `https://`, `ftp://`, `ftps://`, `mailto:`, `internal:` or `external:` that contain no spaces are
+    /// written with [`Worksheet::write_url`] instead.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet-set_autoconvert_urls-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_string(0, 0, "https://github.com", None)?; // plain text
+    /// worksheet.set_autoconvert_urls(true);
+    /// worksheet.write_string(1, 0, "https://github.com", None)?; // hyperlink
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn set_autoconvert_urls(&mut self, enable: bool) {
+        self._workbook
+            .worksheet_state(self.worksheet)
+            .autoconvert_urls = Some(enable);
+    }
+
     /// This function writes a formula or function to the cell specified by row and column:
     /// ```rust
     /// # use xlsxwriter::prelude::*;
@@ -549,6 +1159,20 @@ impl<'a> Worksheet<'a> {
     /// # workbook.close()
     /// # }
     /// ```
+    /// Strings inside a formula are passed to Excel unchanged, so they follow Excel's own quoting rules: string literals are
+    /// enclosed in double quotes and a literal double quote inside a string is written as two double quotes. No other
+    /// escaping is needed for commas or special characters inside string literals:
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_formula-3.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_formula(0, 0, r#"=IF(A1="a,b","yes","no")"#, None)?;
+    /// worksheet.write_formula(1, 0, r#"="She said ""hi"", then left""#, None)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    ///
     /// See also [Working with Formulas](https://libxlsxwriter.github.io/working_with_formulas.html).
     pub fn write_formula(
         &mut self,
@@ -566,12 +1190,12 @@ impl<'a> Worksheet<'a> {
                 c_string_helper.add(formula)?,
                 self._workbook.get_internal_option_format(format)?,
             );
-            if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
-                Ok(())
-            } else {
-                Err(XlsxError::new(result))
+            if result != libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                return Err(XlsxError::new(result));
             }
         }
+        self.record_formula(formula.strip_prefix('=').unwrap_or(formula));
+        Ok(())
     }
 
     /// This function writes an array formula to a cell range. In Excel an array formula is a formula that performs a calculation on a set of values.
@@ -617,11 +1241,133 @@ impl<'a> Worksheet<'a> {
                 c_string_helper.add(formula)?,
                 self._workbook.get_internal_option_format(format)?,
             );
-            if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
-                Ok(())
-            } else {
-                Err(XlsxError::new(result))
+            if result != libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                return Err(XlsxError::new(result));
+            }
+        }
+        self.record_formula(Self::strip_array_formula(formula));
+        Ok(())
+    }
+
+    /// Write an array formula with [`Worksheet::write_array_formula`], together with its results, so the cells of the
+    /// range show values before the formula is recalculated. `results` has one row of values for every row of the
+    /// range:
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_array_formula_num-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_row(0, 0, &[1.into(), 2.into()], None)?;
+    /// worksheet.write_row(1, 0, &[3.into(), 4.into()], None)?;
+    /// worksheet.write_array_formula_num(0, 3, 1, 4, "{=A1:B2*2}", None, &[vec![2., 4.], vec![6., 8.]])?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// An error is returned if the size of `results` doesn't match the range. See [`Worksheet::write_formula_num`] for
+    /// when results are needed.
+    #[allow(clippy::too_many_arguments)]
+    pub fn write_array_formula_num(
+        &mut self,
+        first_row: WorksheetRow,
+        first_col: WorksheetCol,
+        last_row: WorksheetRow,
+        last_col: WorksheetCol,
+        formula: &str,
+        format: Option<&Format>,
+        results: &[Vec<f64>],
+    ) -> Result<(), XlsxError> {
+        let (first_row, last_row) = (first_row.min(last_row), first_row.max(last_row));
+        let (first_col, last_col) = (first_col.min(last_col), first_col.max(last_col));
+        let cols = usize::from(last_col - first_col) + 1;
+        if results.len() as u64 != u64::from(last_row - first_row) + 1
+            || results.iter().any(|x| x.len() != cols)
+        {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ));
+        }
+        let mut c_string_helper = CStringHelper::new();
+        let internal_format = self._workbook.get_internal_option_format(format)?;
+        unsafe {
+            let result = libxlsxwriter_sys::worksheet_write_array_formula_num(
+                self.worksheet,
+                first_row,
+                first_col,
+                last_row,
+                last_col,
+                c_string_helper.add(formula)?,
+                internal_format,
+                results[0][0],
+            );
+            if result != libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                return Err(XlsxError::new(result));
+            }
+            // libxlsxwriter fills the rest of the range with zeros
+            for (row, values) in (first_row..).zip(results.iter()) {
+                for (col, value) in (first_col..).zip(values.iter()) {
+                    if row == first_row && col == first_col {
+                        continue;
+                    }
+                    let result = libxlsxwriter_sys::worksheet_write_number(
+                        self.worksheet,
+                        row,
+                        col,
+                        *value,
+                        internal_format,
+                    );
+                    if result != libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                        return Err(XlsxError::new(result));
+                    }
+                }
+            }
+        }
+        self.record_formula(Self::strip_array_formula(formula));
+        Ok(())
+    }
+
+    /// Return the last formula written with one of the `write_*formula*` functions, as it is stored in the file.
+    ///
+    /// libxlsxwriter removes the leading `=` of a formula, and the braces and `=` of an array formula, so the result
+    /// can be compared in tests of generated formulas:
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_last_written_formula-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_formula(0, 0, "=SUM(B1:B10)", None)?;
+    /// assert_eq!(worksheet.last_written_formula().as_deref(), Some("SUM(B1:B10)"));
+    /// worksheet.write_array_formula(1, 0, 1, 0, "{=SUM(B1:C1*B2:C2)}", None)?;
+    /// assert_eq!(worksheet.last_written_formula().as_deref(), Some("SUM(B1:C1*B2:C2)"));
+    /// # workbook.close()
+    /// # }
+    /// ```
+    ///
+    /// This function is only available with the `formula-tracking` feature, so that formulas are not copied when
+    /// they aren't needed.
+    #[cfg(feature = "formula-tracking")]
+    pub fn last_written_formula(&self) -> Option<String> {
+        self._workbook
+            .worksheet_state(self.worksheet)
+            .last_formula
+            .clone()
+    }
+
+    #[cfg(feature = "formula-tracking")]
+    fn record_formula(&self, formula: &str) {
+        self._workbook.worksheet_state(self.worksheet).last_formula = Some(formula.to_string());
+    }
+
+    #[cfg(not(feature = "formula-tracking"))]
+    fn record_formula(&self, _formula: &str) {}
+
+    /// Strip the braces and the leading `=` of an array formula, the same way as libxlsxwriter does.
+    fn strip_array_formula(formula: &str) -> &str {
+        match formula.strip_prefix('{') {
+            Some(inner) => {
+                let inner = inner.strip_prefix('=').unwrap_or(inner);
+                inner.strip_suffix('}').unwrap_or(inner)
             }
+            None => formula.strip_prefix('=').unwrap_or(formula),
         }
     }
 
@@ -731,14 +1477,33 @@ impl<'a> Worksheet<'a> {
     /// # workbook.close()
     /// # }
     /// ```
-    pub fn write_url(
-        &mut self,
-        row: WorksheetRow,
-        col: WorksheetCol,
-        url: &str,
-        format: Option<&Format>,
-    ) -> Result<(), XlsxError> {
-        unsafe {
+    ///
+    /// An `internal:` link can also point to a name defined with [`Workbook::define_name`], before or after the link
+    /// is written. An error is returned if the target can't be a cell, a range or a name:
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_url-5.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// workbook.define_name("Sales", "=Sheet1!$C$1:$C$10")?;
+    /// worksheet.write_url(0, 0, "internal:Sales", None)?;
+    /// assert!(worksheet.write_url(1, 0, "internal:Sales Q1", None).is_err());
+    /// # workbook.close()
+    /// # }
+    /// ```
+    ///
+    /// Excel allows at most 65,530 hyperlinks in a worksheet. Writing more returns an error with the number of links
+    /// already written.
+    pub fn write_url(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        url: &str,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        self.check_internal_link(url)?;
+        self.check_hyperlink_limit()?;
+        unsafe {
             let result = libxlsxwriter_sys::worksheet_write_url(
                 self.worksheet,
                 row,
@@ -754,6 +1519,140 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function is used to write a URL/hyperlink to a worksheet cell with additional, optional, parameters.
+    ///
+    /// `string` is the text displayed in the cell instead of the URL, and `tooltip` is shown when the user hovers over the link.
+    /// The tooltip can be at most 255 characters long; a longer tooltip is rejected with an error instead of being
+    /// truncated.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_url_opt-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_url_opt(
+    ///     0,
+    ///     0,
+    ///     "http://libxlsxwriter.github.io",
+    ///     None,
+    ///     Some("Read the documentation."),
+    ///     Some("Click here"),
+    /// )?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn write_url_opt(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        url: &str,
+        format: Option<&Format>,
+        string: Option<&str>,
+        tooltip: Option<&str>,
+    ) -> Result<(), XlsxError> {
+        self.check_internal_link(url)?;
+        self.check_hyperlink_limit()?;
+        if let Some(length) = tooltip.map(|x| x.chars().count()) {
+            if length > MAX_TOOLTIP_LENGTH {
+                return Err(XlsxError::tooltip_too_long(length));
+            }
+        }
+        let mut c_string_helper = CStringHelper::new();
+        unsafe {
+            let result = libxlsxwriter_sys::worksheet_write_url_opt(
+                self.worksheet,
+                row,
+                col,
+                c_string_helper.add(url)?,
+                self._workbook.get_internal_option_format(format)?,
+                c_string_helper.add_opt(string)?,
+                c_string_helper.add_opt(tooltip)?,
+            );
+            if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                Ok(())
+            } else {
+                Err(XlsxError::new(result))
+            }
+        }
+    }
+
+    /// Write a hyperlink to a local file. The path may use either `/` or `\` as separator, and a relative path
+    /// is resolved from the directory of the workbook. See [`crate::utility::file_link_url`] for the accepted paths.
+    ///
+    /// If `text` is `None` the path is displayed as it was given.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_file_link-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_file_link(0, 0, "../docs/file.pdf", Some("Manual"), None)?;
+    /// worksheet.write_file_link(1, 0, "..\\data\\report.xlsx#Sheet2!A1", None, None)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn write_file_link(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        path: &str,
+        text: Option<&str>,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        let url = crate::utility::file_link_url(path)?;
+        self.write_url_opt(row, col, &url, format, Some(text.unwrap_or(path)), None)
+    }
+
+    /// Check that the target of an `internal:` link can be a cell, a range or a defined name.
+    fn check_internal_link(&self, url: &str) -> Result<(), XlsxError> {
+        match url.strip_prefix("internal:") {
+            Some(location) if !is_link_target(location.trim()) => {
+                Err(XlsxError::invalid_internal_link(location.trim()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Check that another hyperlink can be added without exceeding the limit of Excel.
+    fn check_hyperlink_limit(&self) -> Result<(), XlsxError> {
+        let count = unsafe { (*self.worksheet).hlink_count } as usize;
+        if count >= MAX_HYPERLINKS {
+            Err(XlsxError::too_many_hyperlinks(count))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Write a hyperlink, detecting whether the target is an email address or a web address.
+    ///
+    /// Email-like targets such as `someone@example.com` are written as `mailto:` links, targets starting with `www.`
+    /// are written as `http://` links and everything else (including `mailto:`, `internal:` and `external:` URLs)
+    /// is passed through unchanged. If `text` is `None` the target itself is displayed, without any added prefix.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_link-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_link(0, 0, "jane.doe@example.com", None, None)?;
+    /// worksheet.write_link(1, 0, "https://github.com", Some("GitHub"), None)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn write_link(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        target: &str,
+        text: Option<&str>,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        let url = link_url(target);
+        let text = text.or(if url == target.trim() {
+            None
+        } else {
+            Some(target)
+        });
+        self.write_url_opt(row, col, &url, format, text, None)
+    }
+
     /// Write an Excel boolean to the cell specified by row and column:
     /// ```rust
     /// # use xlsxwriter::prelude::*;
@@ -824,6 +1723,46 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// Change the format of a cell that has already been written, keeping its value. `None` removes the format.
+    ///
+    /// Writing to a cell again with one of the `write_*` functions replaces both its value and its format, so the last
+    /// write wins. This function is the way to change only the format:
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_rewrite_with_format-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_number(0, 0, 1234.5, None)?;
+    /// worksheet.rewrite_with_format(0, 0, Some(Format::new().set_num_format("#,##0.00")))?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// An error is returned if nothing has been written to the cell. This function doesn't work in `constant_memory`
+    /// mode, where libxlsxwriter doesn't keep the written cells, and always returns an error there.
+    pub fn rewrite_with_format(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        let internal_format = self._workbook.get_internal_option_format(format)?;
+        unsafe {
+            let lxw_row = libxlsxwriter_sys::lxw_worksheet_find_row(self.worksheet, row);
+            let cell = if lxw_row.is_null() {
+                std::ptr::null_mut()
+            } else {
+                libxlsxwriter_sys::lxw_worksheet_find_cell_in_row(lxw_row, col)
+            };
+            if cell.is_null() {
+                return Err(XlsxError::new(
+                    libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+                ));
+            }
+            (*cell).format = internal_format;
+        }
+        Ok(())
+    }
+
     /// This function writes a formula or Excel function to the cell specified by row and column with a user defined numeric result:
     /// ```rust
     /// # use xlsxwriter::prelude::*;
@@ -864,12 +1803,12 @@ impl<'a> Worksheet<'a> {
                 self._workbook.get_internal_option_format(format)?,
                 number,
             );
-            if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
-                Ok(())
-            } else {
-                Err(XlsxError::new(result))
+            if result != libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                return Err(XlsxError::new(result));
             }
         }
+        self.record_formula(formula.strip_prefix('=').unwrap_or(formula));
+        Ok(())
     }
 
     /// This function writes a formula or Excel function to the cell specified by row and column with a user defined string result:
@@ -906,12 +1845,12 @@ impl<'a> Worksheet<'a> {
                 self._workbook.get_internal_option_format(format)?,
                 CString::new(result)?.as_c_str().as_ptr(),
             );
-            if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
-                Ok(())
-            } else {
-                Err(XlsxError::new(result))
+            if result != libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                return Err(XlsxError::new(result));
             }
         }
+        self.record_formula(formula.strip_prefix('=').unwrap_or(formula));
+        Ok(())
     }
 
     /// This function is used to write strings with multiple formats. For example to write the string 'This is bold and this is italic' you would use the following:
@@ -949,7 +1888,8 @@ impl<'a> Worksheet<'a> {
     /// This in then converted to the tuple fragments shown in the example above. For the default format we use None.
     ///
     /// ### Note
-    ///  Excel doesn't allow the use of two consecutive formats in a rich string or an empty string fragment. For either of these conditions a warning is raised and the input to `worksheet.write_rich_string()` is ignored.
+    ///  Excel doesn't allow the use of two consecutive formats in a rich string or an empty string fragment. An empty fragment, an empty list of fragments or a total length of more than 32767 characters is rejected with an error before anything is written.
+    /// A fragment containing a NUL character returns an error, as with the other string functions.
     pub fn write_rich_string(
         &mut self,
         row: WorksheetRow,
@@ -957,6 +1897,22 @@ impl<'a> Worksheet<'a> {
         text: &[(&str, Option<&Format>)],
         format: Option<&Format>,
     ) -> Result<(), XlsxError> {
+        if text.is_empty() {
+            return Err(XlsxError::invalid_rich_string("no fragments"));
+        }
+        if let Some(i) = text.iter().position(|x| x.0.is_empty()) {
+            return Err(XlsxError::invalid_rich_string(&format!(
+                "fragment {} is empty",
+                i
+            )));
+        }
+        if text.iter().map(|x| x.0.chars().count()).sum::<usize>() > STRING_MAX_LENGTH {
+            return Err(XlsxError::invalid_rich_string(&format!(
+                "longer than {} characters",
+                STRING_MAX_LENGTH
+            )));
+        }
+
         let mut c_str: Vec<Vec<u8>> = text
             .iter()
             .map(|x| Ok(CString::new(x.0)?.as_c_str().to_bytes_with_nul().to_vec()))
@@ -994,6 +1950,34 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// Set the height of a row to a multiple of the default height [`LXW_DEF_ROW_HEIGHT`].
+    /// See [`Worksheet::set_column_scale`].
+    pub fn set_row_scale(
+        &mut self,
+        row: WorksheetRow,
+        scale: f64,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        self.set_row(row, LXW_DEF_ROW_HEIGHT * scale, format)
+    }
+
+    /// This function is used to change the default properties of a row, with the height in character units.
+    /// See also [`Worksheet::set_row_pixels`] to set the height in pixels.
+    ///
+    /// The format is the default format of the row: it applies to every cell in the row that is written without a
+    /// format of its own, whether the cell is written before or after this call. It takes precedence over a column
+    /// format set with [`Worksheet::set_column`], and a format passed to a `write_*` function overrides both.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet-set_row-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.set_row(0, LXW_DEF_ROW_HEIGHT, Some(Format::new().set_bg_color(FormatColor::Yellow)))?;
+    /// worksheet.write_string(0, 0, "Yellow", None)?;
+    /// worksheet.write_string(0, 1, "Red", Some(Format::new().set_bg_color(FormatColor::Red)))?;
+    /// # workbook.close()
+    /// # }
+    /// ```
     pub fn set_row(
         &mut self,
         row: WorksheetRow,
@@ -1008,6 +1992,11 @@ impl<'a> Worksheet<'a> {
                 self._workbook.get_internal_option_format(format)?,
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self._workbook.worksheet_state(self.worksheet).set_row(
+                    row,
+                    RowHeight::Height(height),
+                    &RowColOptions::default(),
+                );
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -1023,15 +2012,20 @@ impl<'a> Worksheet<'a> {
         options: &RowColOptions,
     ) -> Result<(), XlsxError> {
         unsafe {
-            let mut options = options.to_internal();
+            let mut internal_options = options.to_internal();
             let result = libxlsxwriter_sys::worksheet_set_row_opt(
                 self.worksheet,
                 row,
                 height,
                 self._workbook.get_internal_option_format(format)?,
-                &mut options,
+                &mut internal_options,
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self._workbook.worksheet_state(self.worksheet).set_row(
+                    row,
+                    RowHeight::Height(height),
+                    options,
+                );
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -1056,6 +2050,11 @@ impl<'a> Worksheet<'a> {
                 self._workbook.get_internal_option_format(format)?,
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self._workbook.worksheet_state(self.worksheet).set_row(
+                    row,
+                    RowHeight::Pixels(pixels),
+                    &RowColOptions::default(),
+                );
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -1070,16 +2069,21 @@ impl<'a> Worksheet<'a> {
         format: Option<&Format>,
         options: &RowColOptions,
     ) -> Result<(), XlsxError> {
-        let mut options = options.to_internal();
+        let mut internal_options = options.to_internal();
         unsafe {
             let result = libxlsxwriter_sys::worksheet_set_row_pixels_opt(
                 self.worksheet,
                 row,
                 pixels,
                 self._workbook.get_internal_option_format(format)?,
-                &mut options,
+                &mut internal_options,
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self._workbook.worksheet_state(self.worksheet).set_row(
+                    row,
+                    RowHeight::Pixels(pixels),
+                    options,
+                );
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -1087,6 +2091,24 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function can be used to change the default properties of a single column or a range of columns, in character units.
+    /// See also [`Worksheet::set_column_pixels`] to set the width in pixels.
+    ///
+    /// The format is the default format of the column: it applies to every cell in the column that is written without
+    /// a format of its own, whether the cell is written before or after this call, and to empty cells when they are
+    /// edited in Excel. A format passed to a `write_*` function always overrides the column format, and a row format
+    /// set with [`Worksheet::set_row`] overrides the column format as well.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet-set_column-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.set_column(0, 0, 20.0, Some(Format::new().set_bold()))?;
+    /// worksheet.write_string(0, 0, "Bold", None)?;
+    /// worksheet.write_string(1, 0, "Italic, not bold", Some(Format::new().set_italic()))?;
+    /// # workbook.close()
+    /// # }
+    /// ```
     pub fn set_column(
         &mut self,
         first_col: WorksheetCol,
@@ -1103,6 +2125,12 @@ impl<'a> Worksheet<'a> {
                 self._workbook.get_internal_option_format(format)?,
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self._workbook.worksheet_state(self.worksheet).set_columns(
+                    first_col,
+                    last_col,
+                    ColumnWidth::Width(width),
+                    &RowColOptions::default(),
+                );
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -1118,7 +2146,7 @@ impl<'a> Worksheet<'a> {
         format: Option<&Format>,
         options: &RowColOptions,
     ) -> Result<(), XlsxError> {
-        let mut options = options.to_internal();
+        let mut internal_options = options.to_internal();
         unsafe {
             let result = libxlsxwriter_sys::worksheet_set_column_opt(
                 self.worksheet,
@@ -1126,9 +2154,15 @@ impl<'a> Worksheet<'a> {
                 last_col,
                 width,
                 self._workbook.get_internal_option_format(format)?,
-                &mut options,
+                &mut internal_options,
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self._workbook.worksheet_state(self.worksheet).set_columns(
+                    first_col,
+                    last_col,
+                    ColumnWidth::Width(width),
+                    options,
+                );
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -1152,6 +2186,12 @@ impl<'a> Worksheet<'a> {
                 self._workbook.get_internal_option_format(format)?,
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self._workbook.worksheet_state(self.worksheet).set_columns(
+                    first_col,
+                    last_col,
+                    ColumnWidth::Pixels(pixels),
+                    &RowColOptions::default(),
+                );
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -1167,7 +2207,7 @@ impl<'a> Worksheet<'a> {
         format: Option<&Format>,
         options: &mut RowColOptions,
     ) -> Result<(), XlsxError> {
-        let mut options = options.to_internal();
+        let mut internal_options = options.to_internal();
         unsafe {
             let result = libxlsxwriter_sys::worksheet_set_column_pixels_opt(
                 self.worksheet,
@@ -1175,9 +2215,322 @@ impl<'a> Worksheet<'a> {
                 last_col,
                 pixels,
                 self._workbook.get_internal_option_format(format)?,
-                &mut options,
+                &mut internal_options,
             );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self._workbook.worksheet_state(self.worksheet).set_columns(
+                    first_col,
+                    last_col,
+                    ColumnWidth::Pixels(pixels),
+                    options,
+                );
+                Ok(())
+            } else {
+                Err(XlsxError::new(result))
+            }
+        }
+    }
+
+    /// Set the width of a range of columns to a multiple of the default width [`LXW_DEF_COL_WIDTH`], so that `1.5`
+    /// makes the columns 50% wider than the default.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_set_column_scale-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.set_column_scale(0, 0, 1.5, None)?;
+    /// worksheet.set_row_scale(0, 2.0, None)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn set_column_scale(
+        &mut self,
+        first_col: WorksheetCol,
+        last_col: WorksheetCol,
+        scale: f64,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        self.set_column(first_col, last_col, LXW_DEF_COL_WIDTH * scale, format)
+    }
+
+    /// Apply a format to a range of columns without changing their width.
+    ///
+    /// Columns that have been sized with one of the `set_column*` functions keep that width and their hidden/outline options,
+    /// other columns keep the default width [`LXW_DEF_COL_WIDTH`].
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_set_column_format-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.set_column(0, 0, 30.0, None)?;
+    /// // Column A is colored and stays 30 characters wide
+    /// worksheet.set_column_format(0, 1, Some(Format::new().set_bg_color(FormatColor::Yellow)))?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn set_column_format(
+        &mut self,
+        first_col: WorksheetCol,
+        last_col: WorksheetCol,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        let (first_col, last_col) = (first_col.min(last_col), first_col.max(last_col));
+        let default_settings = ColumnSettings {
+            width: ColumnWidth::Width(LXW_DEF_COL_WIDTH),
+            options: RowColOptions::default(),
+        };
+        let settings: Vec<ColumnSettings> = {
+            let state = self._workbook.worksheet_state(self.worksheet);
+            (first_col..=last_col)
+                .map(|col| {
+                    state
+                        .columns
+                        .get(&col)
+                        .cloned()
+                        .unwrap_or_else(|| default_settings.clone())
+                })
+                .collect()
+        };
+
+        // Apply the format to each run of columns sharing the same width and options
+        let mut group_first = first_col;
+        for (i, current) in settings.iter().enumerate() {
+            if settings.get(i + 1) == Some(current) {
+                continue;
+            }
+            let group_last = first_col + i as WorksheetCol;
+            match current.width {
+                ColumnWidth::Width(width) => {
+                    self.set_column_opt(group_first, group_last, width, format, &current.options)?;
+                }
+                ColumnWidth::Pixels(pixels) => {
+                    let mut options = current.options.clone();
+                    self.set_column_pixels_opt(
+                        group_first,
+                        group_last,
+                        pixels,
+                        format,
+                        &mut options,
+                    )?;
+                }
+            }
+            group_first = group_last.saturating_add(1);
+        }
+        Ok(())
+    }
+
+    /// Set a number format for a whole column, e.g. a currency format for a column of amounts, without changing its
+    /// width. Numbers written to the column without a format are displayed with it.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_set_column_num_format-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.set_column_num_format(1, "$#,##0.00")?;
+    /// worksheet.write_string(0, 0, "Rent", None)?;
+    /// worksheet.write_number(0, 1, 1250.0, None)?; // $1,250.00
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// A cell written with its own format only uses that format, as in Excel, so the number format has to be added
+    /// to it. The column format replaces any format previously set with [`Worksheet::set_column_format`].
+    pub fn set_column_num_format(
+        &mut self,
+        col: WorksheetCol,
+        num_format: &str,
+    ) -> Result<(), XlsxError> {
+        self.set_column_format(col, col, Some(Format::new().set_num_format(num_format)))
+    }
+
+    /// Group a range of rows one outline level deeper, like Excel's "Group" command. Grouping a range inside an
+    /// existing group creates a nested group, up to Excel's limit of 7 levels.
+    ///
+    /// With `collapsed` the grouped rows are hidden and the summary row, which shows the expand button, is marked as
+    /// collapsed; Excel shows the group expanded without it. The summary row is the row below the group, or the row
+    /// above it if [`Worksheet::outline_settings`] has been called with `symbols_below` off before. The rows keep their
+    /// height and format.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_group_rows-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// for row in 1..10 {
+    ///     worksheet.write_number(row, 0, row.into(), None)?;
+    /// }
+    /// worksheet.write_formula(10, 0, "=SUM(A2:A10)", None)?;
+    /// worksheet.group_rows(1, 9, true)?; // rows 2 to 10, collapsed into row 11
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn group_rows(
+        &mut self,
+        first_row: WorksheetRow,
+        last_row: WorksheetRow,
+        collapsed: bool,
+    ) -> Result<(), XlsxError> {
+        let (first_row, last_row) = (first_row.min(last_row), first_row.max(last_row));
+        for row in first_row..=last_row {
+            let mut options = self.row_settings(row).options;
+            options.level = (options.level + 1).min(OUTLINE_LEVEL_MAX);
+            options.hidden = options.hidden || collapsed;
+            self.set_row_outline(row, &options)?;
+        }
+        let summary_row = if self._workbook.worksheet_state(self.worksheet).summary_above {
+            first_row.checked_sub(1)
+        } else {
+            Some(last_row + 1).filter(|row| u64::from(*row) < u64::from(ROW_COUNT))
+        };
+        if let (true, Some(summary_row)) = (collapsed, summary_row) {
+            let mut options = self.row_settings(summary_row).options;
+            options.collapsed = true;
+            self.set_row_outline(summary_row, &options)?;
+        }
+        Ok(())
+    }
+
+    /// Group a range of columns one outline level deeper. This is the column version of [`Worksheet::group_rows`]:
+    /// with `collapsed` the columns are hidden and the summary column to the right of them, or to the left of them if
+    /// [`Worksheet::outline_settings`] has been called with `symbols_right` off, is marked as collapsed.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_group_columns-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.set_column(1, 3, 12.0, None)?;
+    /// worksheet.group_columns(1, 3, false)?; // B:D
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn group_columns(
+        &mut self,
+        first_col: WorksheetCol,
+        last_col: WorksheetCol,
+        collapsed: bool,
+    ) -> Result<(), XlsxError> {
+        let (first_col, last_col) = (first_col.min(last_col), first_col.max(last_col));
+        for col in first_col..=last_col {
+            let mut settings = self.column_settings(col);
+            settings.options.level = (settings.options.level + 1).min(OUTLINE_LEVEL_MAX);
+            settings.options.hidden = settings.options.hidden || collapsed;
+            self.set_column_outline(col, &settings)?;
+        }
+        let summary_col = if self._workbook.worksheet_state(self.worksheet).summary_left {
+            first_col.checked_sub(1)
+        } else {
+            Some(last_col + 1).filter(|col| u32::from(*col) < COL_COUNT)
+        };
+        if let (true, Some(summary_col)) = (collapsed, summary_col) {
+            let mut settings = self.column_settings(summary_col);
+            settings.options.collapsed = true;
+            self.set_column_outline(summary_col, &settings)?;
+        }
+        Ok(())
+    }
+
+    fn row_settings(&self, row: WorksheetRow) -> RowSettings {
+        let state = self._workbook.worksheet_state(self.worksheet);
+        state
+            .rows
+            .get(&row)
+            .cloned()
+            .unwrap_or_else(|| RowSettings {
+                height: RowHeight::Height(state.default_row_height.unwrap_or(LXW_DEF_ROW_HEIGHT)),
+                options: RowColOptions::default(),
+            })
+    }
+
+    fn column_settings(&self, col: WorksheetCol) -> ColumnSettings {
+        let state = self._workbook.worksheet_state(self.worksheet);
+        state
+            .columns
+            .get(&col)
+            .cloned()
+            .unwrap_or_else(|| ColumnSettings {
+                width: ColumnWidth::Width(LXW_DEF_COL_WIDTH),
+                options: RowColOptions::default(),
+            })
+    }
+
+    /// Change the outline options of a row, keeping its height and the format set by libxlsxwriter.
+    fn set_row_outline(
+        &mut self,
+        row: WorksheetRow,
+        options: &RowColOptions,
+    ) -> Result<(), XlsxError> {
+        let height = self.row_settings(row).height;
+        let mut internal_options = options.to_internal();
+        unsafe {
+            let lxw_row = libxlsxwriter_sys::lxw_worksheet_find_row(self.worksheet, row);
+            let format = if lxw_row.is_null() {
+                std::ptr::null_mut()
+            } else {
+                (*lxw_row).format
+            };
+            let result = match height {
+                RowHeight::Height(height) => libxlsxwriter_sys::worksheet_set_row_opt(
+                    self.worksheet,
+                    row,
+                    height,
+                    format,
+                    &mut internal_options,
+                ),
+                RowHeight::Pixels(pixels) => libxlsxwriter_sys::worksheet_set_row_pixels_opt(
+                    self.worksheet,
+                    row,
+                    pixels,
+                    format,
+                    &mut internal_options,
+                ),
+            };
+            if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self._workbook
+                    .worksheet_state(self.worksheet)
+                    .set_row(row, height, options);
+                Ok(())
+            } else {
+                Err(XlsxError::new(result))
+            }
+        }
+    }
+
+    /// Change the outline options of a column, keeping its width and the format set by libxlsxwriter.
+    fn set_column_outline(
+        &mut self,
+        col: WorksheetCol,
+        settings: &ColumnSettings,
+    ) -> Result<(), XlsxError> {
+        let mut internal_options = settings.options.to_internal();
+        unsafe {
+            let format = if u32::from(col) < u32::from((*self.worksheet).col_formats_max) {
+                *(*self.worksheet).col_formats.add(usize::from(col))
+            } else {
+                std::ptr::null_mut()
+            };
+            let result = match settings.width {
+                ColumnWidth::Width(width) => libxlsxwriter_sys::worksheet_set_column_opt(
+                    self.worksheet,
+                    col,
+                    col,
+                    width,
+                    format,
+                    &mut internal_options,
+                ),
+                ColumnWidth::Pixels(pixels) => libxlsxwriter_sys::worksheet_set_column_pixels_opt(
+                    self.worksheet,
+                    col,
+                    col,
+                    pixels,
+                    format,
+                    &mut internal_options,
+                ),
+            };
+            if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
+                self._workbook.worksheet_state(self.worksheet).set_columns(
+                    col,
+                    col,
+                    settings.width,
+                    &settings.options,
+                );
                 Ok(())
             } else {
                 Err(XlsxError::new(result))
@@ -1241,6 +2594,7 @@ impl<'a> Worksheet<'a> {
     ///         y_offset: 30,
     ///         x_scale: 0.5,
     ///         y_scale: 0.5,
+    ///         ..ImageOptions::default()
     ///     }
     /// )?;
     /// # workbook.close()
@@ -1257,7 +2611,8 @@ impl<'a> Worksheet<'a> {
         filename: &str,
         opt: &ImageOptions,
     ) -> Result<(), XlsxError> {
-        let mut opt_struct = opt.into();
+        let mut c_string_helper = CStringHelper::new();
+        let mut opt_struct = opt.to_c_struct(&mut c_string_helper)?;
         unsafe {
             let result = libxlsxwriter_sys::worksheet_insert_image_opt(
                 self.worksheet,
@@ -1274,6 +2629,92 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// Insert an image scaled so that it covers the cell range from (`first_row`, `first_col`) to (`last_row`, `last_col`).
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_insert_image_in_range-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// // Cover B2:D10
+    /// worksheet.insert_image_in_range(1, 1, 9, 3, "../images/simple1.png")?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    ///
+    /// ### Note
+    /// libxlsxwriter doesn't support setting the second anchor cell of an image directly. Instead the image is anchored at the
+    /// top-left cell of the range and the scale is calculated from the image size and the row heights and column widths of the range,
+    /// so only sizes set with the `set_row*`, `set_column*` and [`Worksheet::set_default_row`] functions of this crate are taken into account.
+    /// Rows that are resized automatically by Excel, for example because of wrapped text, aren't.
+    /// The image still moves and sizes with the cells when they are resized in Excel.
+    pub fn insert_image_in_range(
+        &mut self,
+        first_row: WorksheetRow,
+        first_col: WorksheetCol,
+        last_row: WorksheetRow,
+        last_col: WorksheetCol,
+        filename: &str,
+    ) -> Result<(), XlsxError> {
+        let data = std::fs::read(filename)?;
+        let size = ImageSize::from_bytes(&data).ok_or_else(|| {
+            XlsxError::new(libxlsxwriter_sys::lxw_error_LXW_ERROR_IMAGE_DIMENSIONS)
+        })?;
+        let (width, height) = self
+            ._workbook
+            .worksheet_state(self.worksheet)
+            .range_pixels(first_row, first_col, last_row, last_col);
+        self.insert_image_opt(
+            first_row.min(last_row),
+            first_col.min(last_col),
+            filename,
+            &ImageOptions {
+                x_scale: f64::from(width) / size.display_width(),
+                y_scale: f64::from(height) / size.display_height(),
+                ..ImageOptions::default()
+            },
+        )
+    }
+
+    /// Insert an image from a memory buffer, scaled to fit into a box of `cell_width_px` x `cell_height_px` pixels at
+    /// the top-left corner of the cell. The aspect ratio of the image is kept, so it fills the box in one direction.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_insert_image_fit_cell-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.set_column_pixels(0, 0, 120, None)?;
+    /// worksheet.set_row_pixels(0, 40, None)?;
+    /// let logo = include_bytes!("../../../images/simple1.png");
+    /// worksheet.insert_image_fit_cell(0, 0, &logo[..], 120, 40)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// The size of the image is read from the PNG, JPEG, GIF or BMP header, taking its resolution into account. An
+    /// error is returned if the size can't be read.
+    pub fn insert_image_fit_cell(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        data: &[u8],
+        cell_width_px: u32,
+        cell_height_px: u32,
+    ) -> Result<(), XlsxError> {
+        let size = ImageSize::from_bytes(data).ok_or_else(|| {
+            XlsxError::new(libxlsxwriter_sys::lxw_error_LXW_ERROR_IMAGE_DIMENSIONS)
+        })?;
+        let scale = size.fit_scale(cell_width_px, cell_height_px);
+        self.insert_image_buffer_opt(
+            row,
+            col,
+            data,
+            &ImageOptions {
+                x_scale: scale,
+                y_scale: scale,
+                ..ImageOptions::default()
+            },
+        )
+    }
+
     /// This function can be used to insert a image into a worksheet from a memory buffer:
     /// ```rust
     /// # use xlsxwriter::prelude::*;
@@ -1315,7 +2756,8 @@ impl<'a> Worksheet<'a> {
         buffer: &[u8],
         opt: &ImageOptions,
     ) -> Result<(), XlsxError> {
-        let mut opt_struct = opt.into();
+        let mut c_string_helper = CStringHelper::new();
+        let mut opt_struct = opt.to_c_struct(&mut c_string_helper)?;
         unsafe {
             let result = libxlsxwriter_sys::worksheet_insert_image_buffer_opt(
                 self.worksheet,
@@ -1333,15 +2775,110 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// This function can be used to insert a chart into a worksheet. The chart object must be created first using `Workbook.add_chart()` and configured using the `Chart` functions.
+    ///
+    /// A chart can only be inserted once, into a single worksheet. Inserting the same chart again, into the same or
+    /// another worksheet, returns an error; create a new chart with the same series for every place it should appear.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet-insert_chart-1.xlsx")?;
+    /// # let mut worksheet1 = workbook.add_worksheet(None)?;
+    /// # let mut worksheet2 = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet1.write_number(i, 0, (i*10).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// worksheet1.insert_chart(1, 3, &chart)?;
+    /// assert!(worksheet2.insert_chart(1, 3, &chart).is_err());
+    /// # workbook.close()
+    /// # }
+    /// ```
+    ///
+    /// The chart is 480x288 pixels unless another size is set with [`Chart::set_size_pixels`].
     pub fn insert_chart(
         &mut self,
         row: WorksheetRow,
         column: WorksheetCol,
         chart: &Chart,
+    ) -> Result<(), XlsxError> {
+        let (width, height) = chart
+            .size
+            .unwrap_or((CHART_DEFAULT_WIDTH_PIXELS, CHART_DEFAULT_HEIGHT_PIXELS));
+        self.insert_chart_sized(row, column, chart, width, height)
+    }
+
+    /// Insert a chart scaled to fill the cell range from `first_row`, `first_col` to `last_row`, `last_col`, for
+    /// example to snap charts to the cells of a dashboard layout.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_insert_chart_in_range-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// # for i in 0..5 {
+    /// #     worksheet.write_number(i, 0, (i*10).into(), None)?;
+    /// # }
+    /// let mut chart = workbook.add_chart(ChartType::Column);
+    /// chart.add_series(None, Some("=Sheet1!$A$1:$A$5"))?;
+    /// // B2:H20
+    /// worksheet.insert_chart_in_range(1, 1, 19, 7, &chart)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// The scale is calculated in the same way as for [`Worksheet::insert_image_in_range`], so only row heights and
+    /// column widths set with this crate are taken into account. A size set with [`Chart::set_size_pixels`] is
+    /// ignored.
+    pub fn insert_chart_in_range(
+        &mut self,
+        first_row: WorksheetRow,
+        first_col: WorksheetCol,
+        last_row: WorksheetRow,
+        last_col: WorksheetCol,
+        chart: &Chart,
+    ) -> Result<(), XlsxError> {
+        let (width, height) = self
+            ._workbook
+            .worksheet_state(self.worksheet)
+            .range_pixels(first_row, first_col, last_row, last_col);
+        self.insert_chart_sized(
+            first_row.min(last_row),
+            first_col.min(last_col),
+            chart,
+            width,
+            height,
+        )
+    }
+
+    /// Insert a chart scaled from the default size to `width` x `height` pixels.
+    fn insert_chart_sized(
+        &mut self,
+        row: WorksheetRow,
+        col: WorksheetCol,
+        chart: &Chart,
+        width: u32,
+        height: u32,
     ) -> Result<(), XlsxError> {
         unsafe {
-            let result =
-                libxlsxwriter_sys::worksheet_insert_chart(self.worksheet, row, column, chart.chart);
+            if (*chart.chart).in_use != 0 {
+                return Err(XlsxError::chart_already_inserted());
+            }
+            let mut options = libxlsxwriter_sys::lxw_chart_options {
+                x_offset: 0,
+                y_offset: 0,
+                x_scale: f64::from(width) / f64::from(CHART_DEFAULT_WIDTH_PIXELS),
+                y_scale: f64::from(height) / f64::from(CHART_DEFAULT_HEIGHT_PIXELS),
+                object_position: 0,
+                description: std::ptr::null_mut(),
+                decorative: 0,
+            };
+            let result = libxlsxwriter_sys::worksheet_insert_chart_opt(
+                self.worksheet,
+                row,
+                col,
+                chart.chart,
+                &mut options,
+            );
             if result == libxlsxwriter_sys::lxw_error_LXW_NO_ERROR {
                 Ok(())
             } else {
@@ -1351,6 +2888,26 @@ impl<'a> Worksheet<'a> {
     }
 
     /// The [`Worksheet::merge_range`] function allows cells to be merged together so that they act as a single area.
+    ///
+    /// The string is written to the top-left cell of the range and the remaining cells are padded out with formatted blank cells,
+    /// so the format is applied to every cell in the merged area. This is required for properties such as borders to be displayed
+    /// around the whole region rather than just the first cell:
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_merge_range-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let mut merge_format = Format::new();
+    /// merge_format
+    ///     .set_border(FormatBorder::Thin)
+    ///     .set_align(FormatAlignment::Center)
+    ///     .set_vertical_align(FormatVerticalAlignment::VerticalCenter);
+    /// worksheet.merge_range(1, 1, 3, 3, "Merged Range", Some(&merge_format))?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    ///
+    /// A single cell cannot be merged, and merged ranges must not overlap with each other.
     pub fn merge_range(
         &mut self,
         first_row: WorksheetRow,
@@ -1378,6 +2935,144 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// Merge a range with [`Worksheet::merge_range`] and wrap `text` in it. Excel does not adjust the height of rows
+    /// with merged cells to their content, so the height of the last row of the range is increased if the rows are
+    /// too low for the wrapped text.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_merge_range_wrapped-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let mut note_format = Format::new();
+    /// note_format.set_vertical_align(FormatVerticalAlignment::VerticalTop);
+    /// worksheet.merge_range_wrapped(
+    ///     0,
+    ///     0,
+    ///     0,
+    ///     2,
+    ///     "This note is longer than the three columns it is merged across, so it is wrapped over several lines.",
+    ///     &note_format,
+    /// )?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// The height is estimated from the number of characters, the column widths and the font size of `format`, as
+    /// the text cannot be measured without the font. The row is set with [`Worksheet::set_row`], which replaces its
+    /// format and options.
+    pub fn merge_range_wrapped(
+        &mut self,
+        first_row: WorksheetRow,
+        first_col: WorksheetCol,
+        last_row: WorksheetRow,
+        last_col: WorksheetCol,
+        text: &str,
+        format: &Format,
+    ) -> Result<(), XlsxError> {
+        let mut wrap_format = format.clone();
+        wrap_format.set_text_wrap();
+        self.merge_range(
+            first_row,
+            first_col,
+            last_row,
+            last_col,
+            text,
+            Some(&wrap_format),
+        )?;
+
+        let (first_row, last_row) = (first_row.min(last_row), first_row.max(last_row));
+        let (needed, current, last_row_height) = {
+            let state = self._workbook.worksheet_state(self.worksheet);
+            let needed =
+                state.wrapped_text_height(first_col, last_col, text, format.font_size_points());
+            let (_, height) = state.range_pixels(first_row, first_col, last_row, first_col);
+            let last_row_height = f64::from(state.row_pixels(last_row)) * 0.75;
+            (needed, f64::from(height) * 0.75, last_row_height)
+        };
+        if needed > current {
+            self.set_row(last_row, last_row_height + needed - current, None)?;
+        }
+        Ok(())
+    }
+
+    /// Write a title banner: the columns `first_col..=last_col` of `row` are merged and `text` is centered in them.
+    ///
+    /// The format is applied with horizontal and vertical centering added, so it only needs to specify the look of the
+    /// title, e.g. the font size and fill. A title over a single column is written to that cell without merging.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_title-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let mut title_format = Format::new();
+    /// title_format.set_bold().set_font_size(16.0);
+    /// worksheet.write_title(0, 0, 4, "Sales Report", &title_format)?; // A1:E1
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn write_title(
+        &mut self,
+        row: WorksheetRow,
+        first_col: WorksheetCol,
+        last_col: WorksheetCol,
+        text: &str,
+        format: &Format,
+    ) -> Result<(), XlsxError> {
+        let mut title_format = format.clone();
+        title_format
+            .set_align(FormatAlignment::Center)
+            .set_vertical_align(FormatVerticalAlignment::VerticalCenter);
+        if first_col == last_col {
+            self.write_string(row, first_col, text, Some(&title_format))
+        } else {
+            self.merge_range(
+                row,
+                first_col.min(last_col),
+                row,
+                first_col.max(last_col),
+                text,
+                Some(&title_format),
+            )
+        }
+    }
+
+    /// Return the range of cells that have been written to the worksheet, or `None` if nothing has been written yet.
+    ///
+    /// The range can be passed directly to functions such as [`Worksheet::autofilter`] or [`Worksheet::add_table`]:
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_used_range-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.write_string(0, 0, "Name", None)?;
+    /// worksheet.write_string(0, 1, "Score", None)?;
+    /// worksheet.write_string(1, 0, "Alice", None)?;
+    /// worksheet.write_number(1, 1, 92.0, None)?;
+    ///
+    /// let used = worksheet.used_range().unwrap();
+    /// assert_eq!(used.to_a1(), "A1:B2");
+    /// worksheet.autofilter(used.first_row, used.first_col, used.last_row, used.last_col)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn used_range(&self) -> Option<CellRange> {
+        unsafe {
+            let worksheet = &*self.worksheet;
+            if worksheet.dim_rowmin > worksheet.dim_rowmax
+                || worksheet.dim_colmin > worksheet.dim_colmax
+            {
+                None
+            } else {
+                Some(CellRange::new(
+                    worksheet.dim_rowmin,
+                    worksheet.dim_colmin,
+                    worksheet.dim_rowmax,
+                    worksheet.dim_colmax,
+                ))
+            }
+        }
+    }
+
     /// The [`Worksheet::activate`] function is used to specify which worksheet is initially visible in a multi-sheet workbook.
     pub fn activate(&mut self) {
         unsafe {
@@ -1433,6 +3128,50 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// Freeze the panes above and to the left of an A1 style cell, the way Excel's "Freeze Panes" works with that
+    /// cell selected. `"B2"` freezes the first row and the first column, the same as `freeze_panes(1, 1)`.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_freeze_panes_a1-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.freeze_panes_a1("B2")?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn freeze_panes_a1(&mut self, cell: &str) -> Result<(), XlsxError> {
+        let (row, col) = crate::utility::cell_to_rowcol(cell)?;
+        self.freeze_panes(row, col);
+        Ok(())
+    }
+
+    /// Set up a sticky header: `row` gets the height `height` and the format `format`, and the panes are frozen below it
+    /// so that the header stays visible while scrolling. No data is written, so the header cells can be written before
+    /// or after this call; cells written without a format show the row format.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_setup_header_row-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let mut header_format = Format::new();
+    /// header_format.set_bold().set_bg_color(FormatColor::Silver);
+    /// worksheet.setup_header_row(0, &header_format, 20.0)?;
+    /// worksheet.write_string(0, 0, "Name", None)?;
+    /// worksheet.write_string(0, 1, "Score", None)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn setup_header_row(
+        &mut self,
+        row: WorksheetRow,
+        format: &Format,
+        height: f64,
+    ) -> Result<(), XlsxError> {
+        self.set_row(row, height, Some(format))?;
+        self.freeze_panes(row + 1, 0);
+        Ok(())
+    }
+
     /// The [`Worksheet::split_panes`] function can be used to divide a worksheet into horizontal or vertical regions known as panes.
     /// This function is different from the [`Worksheet::freeze_panes`] function in that the splits between the panes will be visible
     /// to the user and each pane will have its own scroll bars.
@@ -1469,16 +3208,77 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// Select an A1 style range with `active` as the active cell, the way Excel describes a selection.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_set_selection_a1-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.set_selection_a1("A1:C3", "B2")?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// The active cell must be inside the range, otherwise an error is returned. As with [`Worksheet::set_selection`],
+    /// only the first selection of a worksheet is used.
+    pub fn set_selection_a1(&mut self, range: &str, active: &str) -> Result<(), XlsxError> {
+        let range = CellRange::from_a1(range)?;
+        let (row, col) = crate::utility::cell_to_rowcol(active)?;
+        if !(range.first_row..=range.last_row).contains(&row)
+            || !(range.first_col..=range.last_col).contains(&col)
+        {
+            return Err(XlsxError::invalid_cell_reference(active));
+        }
+        unsafe {
+            if !(*(*self.worksheet).selections).stqh_first.is_null() {
+                return Ok(());
+            }
+        }
+        self.set_selection(row, col, range.last_row, range.last_col);
+        unsafe {
+            // libxlsxwriter takes the active cell from the first corner, so set the range afterwards.
+            let selection = (*(*self.worksheet).selections).stqh_first;
+            if !selection.is_null() {
+                let sqref = CString::new(range.to_a1())?;
+                let sqref = sqref.as_bytes_with_nul();
+                if sqref.len() <= (*selection).sqref.len() {
+                    for (dst, src) in (*selection).sqref.iter_mut().zip(sqref.iter()) {
+                        *dst = *src as c_char;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub fn set_landscape(&mut self) {
         unsafe {
             libxlsxwriter_sys::worksheet_set_landscape(self.worksheet);
         }
+        self._workbook.worksheet_state(self.worksheet).landscape = true;
     }
 
     pub fn set_portrait(&mut self) {
         unsafe {
             libxlsxwriter_sys::worksheet_set_portrait(self.worksheet);
         }
+        self._workbook.worksheet_state(self.worksheet).landscape = false;
+    }
+
+    /// Return `true` if the worksheet is printed in landscape orientation, set with [`Worksheet::set_landscape`] or
+    /// a [`PageSetup`]. Worksheets are printed in portrait orientation by default.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_is_landscape-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// assert!(!worksheet.is_landscape());
+    /// worksheet.set_landscape();
+    /// assert!(worksheet.is_landscape());
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn is_landscape(&self) -> bool {
+        self._workbook.worksheet_state(self.worksheet).landscape
     }
 
     pub fn set_page_view(&mut self) {
@@ -1603,10 +3403,28 @@ impl<'a> Worksheet<'a> {
         }
     }
 
-    pub fn set_zoom(&mut self, scale: u16) {
+    /// Set the worksheet zoom factor in percent, in the range `10..=400`. The default is `100`.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_set_zoom-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.set_zoom(75)?;
+    /// assert!(worksheet.set_zoom(500).is_err());
+    /// # workbook.close()
+    /// # }
+    /// ```
+    ///
+    /// libxlsxwriter writes a single zoom factor, used for the normal view. Excel's page break preview keeps its own
+    /// zoom, which cannot be set.
+    pub fn set_zoom(&mut self, scale: u16) -> Result<(), XlsxError> {
+        if !(ZOOM_MIN..=ZOOM_MAX).contains(&scale) {
+            return Err(XlsxError::invalid_zoom(scale));
+        }
         unsafe {
             libxlsxwriter_sys::worksheet_set_zoom(self.worksheet, scale);
         }
+        Ok(())
     }
 
     pub fn gridlines(&mut self, option: GridLines) {
@@ -1694,6 +3512,41 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// Fit the printed worksheet on a single page, in landscape orientation if the used range is wider than it is
+    /// tall and in portrait orientation otherwise. Call it after the data and the column widths and row heights
+    /// have been set, since they are used to decide the orientation.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_fit_to_one_page-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// for col in 0..20 {
+    ///     worksheet.write_number(0, col, col.into(), None)?;
+    /// }
+    /// worksheet.fit_to_one_page(); // landscape
+    /// # workbook.close()
+    /// # }
+    /// ```
+    pub fn fit_to_one_page(&mut self) {
+        let landscape =
+            match self.used_range() {
+                Some(used) => {
+                    let (width, height) = self
+                        ._workbook
+                        .worksheet_state(self.worksheet)
+                        .range_pixels(used.first_row, used.first_col, used.last_row, used.last_col);
+                    width > height
+                }
+                None => false,
+            };
+        if landscape {
+            self.set_landscape();
+        } else {
+            self.set_portrait();
+        }
+        self.fit_to_pages(1, 1);
+    }
+
     pub fn set_start_page(&mut self, start_page: u16) {
         unsafe {
             libxlsxwriter_sys::worksheet_set_start_page(self.worksheet, start_page);
@@ -1718,12 +3571,44 @@ impl<'a> Worksheet<'a> {
         }
     }
 
+    /// Set the color of the worksheet tab.
+    ///
+    /// Any [`FormatColor`] can be used, including [`FormatColor::Custom`] RGB values and colors
+    /// resolved from the workbook theme with [`FormatColor::theme`].
     pub fn set_tab_color(&mut self, color: FormatColor) {
         unsafe {
             libxlsxwriter_sys::worksheet_set_tab_color(self.worksheet, color.value());
         }
     }
 
+    /// Protect the worksheet from modification. The password is optional, pass `""` for none.
+    ///
+    /// ### Editable ranges
+    /// libxlsxwriter doesn't support Excel's "Allow Edit Ranges", so a range can't be given its own password.
+    /// Instead, write the cells that should stay editable with a format that has `Format.set_unlocked()`:
+    /// every other cell is locked by default and can't be changed once the sheet is protected. Keep unlocked
+    /// cells selectable by turning off `no_select_unlocked_cells`.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_protect-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let mut unlocked = Format::new();
+    /// unlocked.set_unlocked();
+    /// worksheet.write_string(0, 0, "Enter amounts:", None)?;
+    /// for row in 1..=5 {
+    ///     worksheet.write_blank(row, 1, Some(&unlocked))?; // B2:B6 stays editable
+    /// }
+    /// worksheet.protect(
+    ///     "secret",
+    ///     &Protection {
+    ///         no_select_unlocked_cells: false,
+    ///         ..Protection::new()
+    ///     },
+    /// );
+    /// # workbook.close()
+    /// # }
+    /// ```
     pub fn protect(&mut self, password: &str, protection: &Protection) {
         unsafe {
             libxlsxwriter_sys::worksheet_protect(
@@ -1750,6 +3635,9 @@ impl<'a> Worksheet<'a> {
                 convert_bool(auto_style),
             );
         }
+        let mut state = self._workbook.worksheet_state(self.worksheet);
+        state.summary_above = !symbols_below;
+        state.summary_left = !symbols_right;
     }
 
     pub fn set_default_row(&mut self, height: f64, hide_unused_rows: bool) {
@@ -1760,9 +3648,30 @@ impl<'a> Worksheet<'a> {
                 convert_bool(hide_unused_rows),
             );
         }
+        self._workbook
+            .worksheet_state(self.worksheet)
+            .default_row_height = Some(height);
     }
 
+    /// Set the VBA name for the worksheet. By default this is the same as the sheet name: i.e., `Sheet1` etc.
+    ///
+    /// The name must be a valid VBA identifier: it starts with a letter, contains only letters, digits and
+    /// underscores and is at most 31 characters long. Other names are rejected with an error because they
+    /// break macro-enabled workbooks.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet-set_vba_name-1.xlsm")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// worksheet.set_vba_name("MySheet1")?;
+    /// assert!(worksheet.set_vba_name("My Sheet").is_err());
+    /// # workbook.close()
+    /// # }
+    /// ```
     pub fn set_vba_name(&mut self, name: &str) -> Result<(), XlsxError> {
+        if !is_valid_vba_name(name) {
+            return Err(XlsxError::invalid_vba_name(name));
+        }
         unsafe {
             let result = libxlsxwriter_sys::worksheet_set_vba_name(
                 self.worksheet,
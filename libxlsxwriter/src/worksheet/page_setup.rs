@@ -0,0 +1,77 @@
+use super::{PaperType, Worksheet, WorksheetRow};
+use crate::XlsxError;
+
+/// Print settings that can be applied to a worksheet in one call with [`Worksheet::set_page_setup`], or to every
+/// new worksheet with [`crate::Workbook::set_default_page_setup`].
+///
+/// Fields left as `None` or `false` keep the worksheet defaults.
+/// ```rust
+/// # use xlsxwriter::prelude::*;
+/// # fn main() -> Result<(), XlsxError> {
+/// # let workbook = Workbook::new("test-worksheet_page_setup-1.xlsx")?;
+/// # let mut worksheet = workbook.add_worksheet(None)?;
+/// let page_setup = PageSetup {
+///     landscape: true,
+///     paper: Some(PaperType::A4),
+///     fit_to_pages: Some((1, 0)),
+///     repeat_rows: Some((0, 0)),
+///     ..PageSetup::new()
+/// };
+/// worksheet.set_page_setup(&page_setup)?;
+/// # workbook.close()
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+pub struct PageSetup {
+    pub landscape: bool,
+    pub paper: Option<PaperType>,
+    pub header: Option<String>,
+    pub footer: Option<String>,
+    /// Fit the printed area to `(width, height)` pages, see [`Worksheet::fit_to_pages`].
+    pub fit_to_pages: Option<(u16, u16)>,
+    pub print_scale: Option<u16>,
+    /// Rows printed at the top of every page, as `(first_row, last_row)`.
+    pub repeat_rows: Option<(WorksheetRow, WorksheetRow)>,
+    pub center_horizontally: bool,
+    pub center_vertically: bool,
+}
+
+impl PageSetup {
+    pub fn new() -> PageSetup {
+        PageSetup::default()
+    }
+}
+
+impl<'a> Worksheet<'a> {
+    /// Apply all settings of a [`PageSetup`] to the worksheet.
+    pub fn set_page_setup(&mut self, page_setup: &PageSetup) -> Result<(), XlsxError> {
+        if page_setup.landscape {
+            self.set_landscape();
+        }
+        if let Some(paper) = page_setup.paper {
+            self.set_paper(paper);
+        }
+        if let Some(header) = &page_setup.header {
+            self.set_header(header)?;
+        }
+        if let Some(footer) = &page_setup.footer {
+            self.set_footer(footer)?;
+        }
+        if let Some((width, height)) = page_setup.fit_to_pages {
+            self.fit_to_pages(width, height);
+        }
+        if let Some(scale) = page_setup.print_scale {
+            self.set_print_scale(scale);
+        }
+        if let Some((first_row, last_row)) = page_setup.repeat_rows {
+            self.repeat_rows(first_row, last_row)?;
+        }
+        if page_setup.center_horizontally {
+            self.center_horizontally();
+        }
+        if page_setup.center_vertically {
+            self.center_vertically();
+        }
+        Ok(())
+    }
+}
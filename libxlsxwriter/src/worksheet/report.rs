@@ -0,0 +1,129 @@
+use super::{CellValue, Worksheet, WorksheetCol, WorksheetRow, ROW_COUNT};
+use crate::{Format, XlsxError};
+use std::convert::TryFrom;
+
+/// Formats of [`Worksheet::write_report_table`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ReportStyle {
+    /// Format of the header row.
+    pub header_format: Format,
+    /// Format of the data rows, or `None` to write them without a format.
+    pub body_format: Option<Format>,
+    /// Format used instead of `body_format` for every second data row, starting with the second, or `None` for no
+    /// banding. It replaces the body format, so it should include the number format and font of the body.
+    pub band_format: Option<Format>,
+}
+
+impl ReportStyle {
+    pub fn new() -> ReportStyle {
+        ReportStyle::default()
+    }
+}
+
+impl<'a> Worksheet<'a> {
+    /// Write a report table: a header row with `style.header_format`, the `rows` below it with optional banding, an
+    /// autofilter over the whole table and frozen panes below the header row.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_report_table-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let mut style = ReportStyle::new();
+    /// style.header_format.set_bold().set_bg_color(FormatColor::Silver);
+    /// let mut band_format = Format::new();
+    /// band_format.set_bg_color(FormatColor::Custom(0xEE_EE_EE));
+    /// style.band_format = Some(band_format);
+    ///
+    /// let rows: Vec<Vec<CellValue>> = vec![
+    ///     vec!["East".into(), 1200.into()],
+    ///     vec!["West".into(), 950.into()],
+    ///     vec!["North".into(), 1010.into()],
+    /// ];
+    /// worksheet.write_report_table(0, 0, &["Region", "Sales"], &rows, &style)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// The table is as wide as the header or the longest row, and only the rows down to the header are frozen. An error
+    /// is returned if there are no headers.
+    pub fn write_report_table(
+        &mut self,
+        start_row: WorksheetRow,
+        start_col: WorksheetCol,
+        headers: &[&str],
+        rows: &[Vec<CellValue>],
+        style: &ReportStyle,
+    ) -> Result<(), XlsxError> {
+        let width = rows.iter().map(Vec::len).fold(headers.len(), usize::max);
+        let out_of_range =
+            || XlsxError::new(libxlsxwriter_sys::lxw_error_LXW_ERROR_WORKSHEET_INDEX_OUT_OF_RANGE);
+        if headers.is_empty() {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_PARAMETER_VALIDATION,
+            ));
+        }
+        let last_col = WorksheetCol::try_from(width - 1)
+            .ok()
+            .and_then(|x| start_col.checked_add(x))
+            .ok_or_else(out_of_range)?;
+        let last_row = WorksheetRow::try_from(rows.len())
+            .ok()
+            .and_then(|x| start_row.checked_add(x))
+            .ok_or_else(out_of_range)?;
+
+        for (col, header) in (start_col..).zip(headers.iter()) {
+            self.write_string(start_row, col, header, Some(&style.header_format))?;
+        }
+        for (i, (row, values)) in (start_row + 1..).zip(rows.iter()).enumerate() {
+            let format = match &style.band_format {
+                Some(band_format) if i % 2 == 1 => Some(band_format),
+                _ => style.body_format.as_ref(),
+            };
+            for (col, value) in (start_col..).zip(values.iter()) {
+                self.write_value(row, col, value, format)?;
+            }
+        }
+        self.autofilter(start_row, start_col, last_row, last_col)?;
+        self.freeze_panes(start_row + 1, 0);
+        Ok(())
+    }
+
+    /// Write rows of values with alternating formats: `even_format` for the first row and every second row after
+    /// it, and `odd_format` for the rows in between.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_banded_block-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let rows: Vec<Vec<CellValue>> = vec![
+    ///     vec!["East".into(), 1200.into()],
+    ///     vec!["West".into(), 950.into()],
+    ///     vec!["North".into(), 1010.into()],
+    /// ];
+    /// let mut stripe = Format::new();
+    /// stripe.set_bg_color(FormatColor::Custom(0xEE_EE_EE));
+    /// worksheet.write_banded_block(0, 0, &rows, None, Some(&stripe))?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// Rows are written with [`Worksheet::write_row`], so rows may have different lengths, and blank values are only
+    /// written in rows with a format.
+    pub fn write_banded_block(
+        &mut self,
+        start_row: WorksheetRow,
+        start_col: WorksheetCol,
+        rows: &[Vec<CellValue>],
+        even_format: Option<&Format>,
+        odd_format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        if u64::from(start_row) + rows.len() as u64 > u64::from(ROW_COUNT) {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_WORKSHEET_INDEX_OUT_OF_RANGE,
+            ));
+        }
+        for (i, (row, values)) in (start_row..).zip(rows.iter()).enumerate() {
+            let format = if i % 2 == 0 { even_format } else { odd_format };
+            self.write_row(row, start_col, values, format)?;
+        }
+        Ok(())
+    }
+}
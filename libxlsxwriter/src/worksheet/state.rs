@@ -0,0 +1,186 @@
+use super::{
+    IgnoreError, RowColOptions, WorksheetCol, WorksheetRow, LXW_DEF_COL_WIDTH_PIXELS,
+    LXW_DEF_ROW_HEIGHT,
+};
+use std::collections::BTreeMap;
+
+/// Width of the widest digit of the default font, used by Excel to convert column widths to pixels.
+const MAX_DIGIT_WIDTH: f64 = 7.0;
+/// Padding added by Excel to each column, in pixels.
+const COLUMN_PADDING: f64 = 5.0;
+/// Size of the default font in points, which the default column width and row height are based on.
+const DEFAULT_FONT_SIZE: f64 = 11.0;
+
+/// Column width as it was passed to `set_column*()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ColumnWidth {
+    Width(f64),
+    Pixels(u32),
+}
+
+impl ColumnWidth {
+    /// Convert the width to pixels the same way as libxlsxwriter does when positioning objects.
+    pub(crate) fn pixels(self) -> u32 {
+        match self {
+            ColumnWidth::Width(width) if width <= 0.0 => 0,
+            ColumnWidth::Width(width) if width < 1.0 => {
+                (width * (MAX_DIGIT_WIDTH + COLUMN_PADDING) + 0.5) as u32
+            }
+            ColumnWidth::Width(width) => {
+                (width * MAX_DIGIT_WIDTH + 0.5) as u32 + COLUMN_PADDING as u32
+            }
+            ColumnWidth::Pixels(pixels) => pixels,
+        }
+    }
+}
+
+/// Row height as it was passed to `set_row*()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum RowHeight {
+    Height(f64),
+    Pixels(u32),
+}
+
+impl RowHeight {
+    pub(crate) fn pixels(self) -> u32 {
+        match self {
+            RowHeight::Height(height) => (4.0 / 3.0 * height.max(0.0)) as u32,
+            RowHeight::Pixels(pixels) => pixels,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ColumnSettings {
+    pub(crate) width: ColumnWidth,
+    pub(crate) options: RowColOptions,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct RowSettings {
+    pub(crate) height: RowHeight,
+    pub(crate) options: RowColOptions,
+}
+
+/// Settings of a worksheet that libxlsxwriter does not allow to be read back.
+///
+/// The state is owned by the [`crate::Workbook`] so that every [`super::Worksheet`] handle of the same sheet sees it.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct WorksheetState {
+    pub(crate) columns: BTreeMap<WorksheetCol, ColumnSettings>,
+    pub(crate) rows: BTreeMap<WorksheetRow, RowSettings>,
+    pub(crate) default_row_height: Option<f64>,
+    /// `None` uses the workbook setting, see [`crate::Workbook::set_string_url_conversion`].
+    pub(crate) autoconvert_urls: Option<bool>,
+    /// Ranges passed to [`super::Worksheet::ignore_errors`], written when the workbook is closed.
+    pub(crate) ignored_errors: BTreeMap<IgnoreError, Vec<String>>,
+    /// Header row and last column of [`super::Worksheet::add_filtered_header`].
+    pub(crate) filtered_header: Option<(WorksheetRow, WorksheetCol)>,
+    /// Set by [`super::Worksheet::show_comments`], written when the workbook is closed.
+    pub(crate) show_comments: bool,
+    /// Formula returned by [`super::Worksheet::last_written_formula`].
+    #[cfg(feature = "formula-tracking")]
+    pub(crate) last_formula: Option<String>,
+    /// Summary rows are above their groups, set by [`super::Worksheet::outline_settings`].
+    pub(crate) summary_above: bool,
+    /// Summary columns are left of their groups, set by [`super::Worksheet::outline_settings`].
+    pub(crate) summary_left: bool,
+    /// Set by [`super::Worksheet::set_landscape`] and [`super::Worksheet::set_portrait`].
+    pub(crate) landscape: bool,
+}
+
+impl WorksheetState {
+    pub(crate) fn set_columns(
+        &mut self,
+        first_col: WorksheetCol,
+        last_col: WorksheetCol,
+        width: ColumnWidth,
+        options: &RowColOptions,
+    ) {
+        for col in first_col.min(last_col)..=first_col.max(last_col) {
+            self.columns.insert(
+                col,
+                ColumnSettings {
+                    width,
+                    options: options.clone(),
+                },
+            );
+        }
+    }
+
+    pub(crate) fn set_row(
+        &mut self,
+        row: WorksheetRow,
+        height: RowHeight,
+        options: &RowColOptions,
+    ) {
+        self.rows.insert(
+            row,
+            RowSettings {
+                height,
+                options: options.clone(),
+            },
+        );
+    }
+
+    /// Width of a column in pixels. Hidden columns have no width.
+    pub(crate) fn column_pixels(&self, col: WorksheetCol) -> u32 {
+        match self.columns.get(&col) {
+            Some(settings) if settings.options.hidden => 0,
+            Some(settings) => settings.width.pixels(),
+            None => LXW_DEF_COL_WIDTH_PIXELS,
+        }
+    }
+
+    /// Height of a row in pixels. Hidden rows have no height.
+    pub(crate) fn row_pixels(&self, row: WorksheetRow) -> u32 {
+        match self.rows.get(&row) {
+            Some(settings) if settings.options.hidden => 0,
+            Some(settings) => settings.height.pixels(),
+            None => {
+                RowHeight::Height(self.default_row_height.unwrap_or(LXW_DEF_ROW_HEIGHT)).pixels()
+            }
+        }
+    }
+
+    /// Estimate the height in points that `text` needs when it is wrapped in the columns `first_col..=last_col`.
+    ///
+    /// Every character is assumed to be as wide as a digit, so the estimate is only approximate for proportional text.
+    pub(crate) fn wrapped_text_height(
+        &self,
+        first_col: WorksheetCol,
+        last_col: WorksheetCol,
+        text: &str,
+        font_size: Option<f64>,
+    ) -> f64 {
+        let scale = font_size.unwrap_or(DEFAULT_FONT_SIZE) / DEFAULT_FONT_SIZE;
+        let width: u32 = (first_col.min(last_col)..=first_col.max(last_col))
+            .map(|col| self.column_pixels(col))
+            .sum();
+        let chars_per_line = (((f64::from(width) - COLUMN_PADDING) / (MAX_DIGIT_WIDTH * scale))
+            .floor() as usize)
+            .max(1);
+        let lines: usize = text
+            .split('\n')
+            .map(|line| ((line.chars().count() + chars_per_line - 1) / chars_per_line).max(1))
+            .sum();
+        lines as f64 * LXW_DEF_ROW_HEIGHT * scale
+    }
+
+    /// Size of a cell range in pixels, as `(width, height)`.
+    pub(crate) fn range_pixels(
+        &self,
+        first_row: WorksheetRow,
+        first_col: WorksheetCol,
+        last_row: WorksheetRow,
+        last_col: WorksheetCol,
+    ) -> (u32, u32) {
+        let width = (first_col.min(last_col)..=first_col.max(last_col))
+            .map(|col| self.column_pixels(col))
+            .sum();
+        let height = (first_row.min(last_row)..=first_row.max(last_row))
+            .map(|row| self.row_pixels(row))
+            .sum();
+        (width, height)
+    }
+}
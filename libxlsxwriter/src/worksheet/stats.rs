@@ -0,0 +1,98 @@
+use super::{Worksheet, WorksheetCol, WorksheetRow, COL_COUNT};
+use crate::XlsxError;
+
+/// Labels of the rows written by [`Worksheet::write_stats_block`]. The default labels are in English.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StatsLabels {
+    pub count: String,
+    pub mean: String,
+    pub min: String,
+    pub max: String,
+    pub stddev: String,
+}
+
+impl Default for StatsLabels {
+    fn default() -> Self {
+        StatsLabels {
+            count: "Count".to_string(),
+            mean: "Mean".to_string(),
+            min: "Min".to_string(),
+            max: "Max".to_string(),
+            stddev: "Std. dev.".to_string(),
+        }
+    }
+}
+
+impl StatsLabels {
+    pub fn new() -> StatsLabels {
+        StatsLabels::default()
+    }
+}
+
+/// Count, mean, min, max and sample standard deviation of the values that are not NaN. The statistics that are not
+/// defined for the number of values are `None`.
+fn summarize(values: &[f64]) -> [Option<f64>; 5] {
+    let values: Vec<f64> = values.iter().copied().filter(|x| !x.is_nan()).collect();
+    let count = values.len() as f64;
+    if values.is_empty() {
+        return [Some(0.0), None, None, None, None];
+    }
+    let mean = values.iter().sum::<f64>() / count;
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let stddev = if values.len() > 1 {
+        let squares: f64 = values.iter().map(|x| (x - mean).powi(2)).sum();
+        Some((squares / (count - 1.0)).sqrt())
+    } else {
+        None
+    };
+    [Some(count), Some(mean), Some(min), Some(max), stddev]
+}
+
+impl<'a> Worksheet<'a> {
+    /// Write a summary of `values` as five labeled rows: the count, mean, min, max and standard deviation. The labels
+    /// are written in `start_col` and the values in the column on their right.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_write_stats_block-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let values = [3.5, 4.0, f64::NAN, 6.5];
+    /// worksheet.write_stats_block(0, 0, &values, &StatsLabels::default())?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    /// NaN values are skipped. The standard deviation is the sample standard deviation, as calculated by the `STDEV`
+    /// function of Excel. The cells of the statistics that are not defined, such as the mean of no values, are left
+    /// empty.
+    pub fn write_stats_block(
+        &mut self,
+        start_row: WorksheetRow,
+        start_col: WorksheetCol,
+        values: &[f64],
+        labels: &StatsLabels,
+    ) -> Result<(), XlsxError> {
+        if u32::from(start_col) + 1 >= COL_COUNT {
+            return Err(XlsxError::new(
+                libxlsxwriter_sys::lxw_error_LXW_ERROR_WORKSHEET_INDEX_OUT_OF_RANGE,
+            ));
+        }
+        let labels = [
+            &labels.count,
+            &labels.mean,
+            &labels.min,
+            &labels.max,
+            &labels.stddev,
+        ];
+        for ((row, label), value) in (start_row..)
+            .zip(labels.iter())
+            .zip(summarize(values).iter())
+        {
+            self.write_string(row, start_col, label, None)?;
+            if let Some(value) = value {
+                self.write_number(row, start_col + 1, *value, None)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use super::{PageSetup, Worksheet, WorksheetCol, WorksheetRow};
+use crate::{Format, XlsxError};
+
+/// Settings shared by the sheets of a report, applied with [`Worksheet::apply_template`] or
+/// [`crate::Workbook::add_worksheet_from_template`].
+/// ```rust
+/// # use xlsxwriter::prelude::*;
+/// # fn main() -> Result<(), XlsxError> {
+/// # let workbook = Workbook::new("test-worksheet-sheet_template-1.xlsx")?;
+/// let mut template = SheetTemplate::new();
+/// template.column_widths = vec![(0, 0, 12.0), (1, 3, 18.0)];
+/// template.headers = vec!["Date".to_string(), "Region".to_string(), "Sales".to_string()];
+/// template.header_format.set_bold();
+/// template.freeze_panes = Some((1, 0));
+/// let mut january = workbook.add_worksheet_from_template(Some("January"), &template)?;
+/// let mut february = workbook.add_worksheet_from_template(Some("February"), &template)?;
+/// # workbook.close()
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+pub struct SheetTemplate {
+    /// Column widths in character units, as `(first_col, last_col, width)`.
+    pub column_widths: Vec<(WorksheetCol, WorksheetCol, f64)>,
+    /// Labels written to the first row.
+    pub headers: Vec<String>,
+    /// Format of the header labels.
+    pub header_format: Format,
+    /// Freeze the panes above and left of this row and column.
+    pub freeze_panes: Option<(WorksheetRow, WorksheetCol)>,
+    /// Print settings, applied after the default page setup of the workbook.
+    pub page_setup: Option<PageSetup>,
+}
+
+impl SheetTemplate {
+    pub fn new() -> SheetTemplate {
+        SheetTemplate::default()
+    }
+}
+
+impl<'a> Worksheet<'a> {
+    /// Apply the column widths, header row, frozen panes and page setup of a [`SheetTemplate`].
+    pub fn apply_template(&mut self, template: &SheetTemplate) -> Result<(), XlsxError> {
+        for (first_col, last_col, width) in template.column_widths.iter() {
+            self.set_column(*first_col, *last_col, *width, None)?;
+        }
+        for (col, header) in (0..).zip(template.headers.iter()) {
+            self.write_string(0, col, header, Some(&template.header_format))?;
+        }
+        if let Some((row, col)) = template.freeze_panes {
+            self.freeze_panes(row, col);
+        }
+        if let Some(page_setup) = &template.page_setup {
+            self.set_page_setup(page_setup)?;
+        }
+        Ok(())
+    }
+}
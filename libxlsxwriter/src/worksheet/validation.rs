@@ -3,16 +3,22 @@ use crate::{
     XlsxError,
 };
 
-use super::DateTime;
+use super::{CellRange, DateTime};
+use crate::utility::col_to_name;
 use std::ffi::CString;
 use std::os::raw::c_char;
 
+/// Maximum length of the comma separated values of [`DataValidationType::List`], in characters.
+const MAX_LIST_LENGTH: usize = 255;
+
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub enum DataValidationType {
     Integer{ignore_blank: bool, number_options: DataValidationNumberOptions<i64> },
     IntegerFormula{ignore_blank: bool, formula: String },
     Decimal{ignore_blank: bool, number_options: DataValidationNumberOptions<f64> },
     DecimalFormula{ignore_blank: bool, formula: String },
+    /// A list of values. Excel limits the values joined with commas to 255 characters, a longer list is rejected
+    /// with an error; see [`DataValidation::list_from_range`] for long lists.
     List{ignore_blank: bool, dropdown: bool, values: Vec<String> },
     ListFormula{ignore_blank: bool, formula: String },
     Date{ignore_blank: bool, number_options: DataValidationNumberOptions<DateTime> },
@@ -21,6 +27,8 @@ pub enum DataValidationType {
     TimeFormula{ignore_blank: bool, formula: String },
     Length{ignore_blank: bool, number_options: DataValidationNumberOptions<usize> },
     LengthFormula{ignore_blank: bool, formula: String },
+    /// Validate with an arbitrary formula such as `=ISNUMBER(A1)`. The value is accepted if the formula returns `TRUE`.
+    /// Relative references in the formula refer to the top-left cell of the validated range.
     CustomFormula{ignore_blank: bool, formula: String },
     Any,
 }
@@ -146,7 +154,63 @@ impl DataValidationErrorType {
     }
 }
 
+/// Format a range as an absolute reference such as `'Sheet 2'!$A$1:$A$10`.
+fn absolute_range(sheet_name: Option<&str>, range: &CellRange) -> String {
+    let cell = |row: WorksheetRow, col: WorksheetCol| {
+        format!("${}${}", col_to_name(col), u64::from(row) + 1)
+    };
+    let reference = if range.first_row == range.last_row && range.first_col == range.last_col {
+        cell(range.first_row, range.first_col)
+    } else {
+        format!(
+            "{}:{}",
+            cell(range.first_row, range.first_col),
+            cell(range.last_row, range.last_col)
+        )
+    };
+    match sheet_name {
+        Some(sheet_name) => format!("'{}'!{}", sheet_name.replace('\'', "''"), reference),
+        None => reference,
+    }
+}
+
 impl DataValidation {
+    /// Create a dropdown list with the values of a range of cells, on the sheet `sheet_name` or on the validated
+    /// worksheet if it is `None`. Unlike [`DataValidationType::List`], which Excel limits to 255 characters, there is
+    /// no limit on the number or length of the values.
+    /// ```rust
+    /// # use xlsxwriter::prelude::*;
+    /// # use xlsxwriter::worksheet::validation::*;
+    /// # fn main() -> Result<(), XlsxError> {
+    /// # let workbook = Workbook::new("test-worksheet_validation-list_from_range-1.xlsx")?;
+    /// # let mut worksheet = workbook.add_worksheet(None)?;
+    /// let mut countries = workbook.add_worksheet(Some("Countries"))?;
+    /// for (row, name) in ["Australia", "Brazil", "Canada"].iter().enumerate() {
+    ///     countries.write_string(row as WorksheetRow, 0, name, None)?;
+    /// }
+    /// // Same as `ListFormula` with the formula "='Countries'!$A$1:$A$3"
+    /// let validation = DataValidation::list_from_range(Some("Countries"), &CellRange::new(0, 0, 2, 0), None, None);
+    /// worksheet.data_validation_range(0, 0, 99, 0, &validation)?;
+    /// # workbook.close()
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn list_from_range(
+        sheet_name: Option<&str>,
+        range: &CellRange,
+        input_message: Option<InputMessageOptions>,
+        error_alert: Option<ErrorAlertOptions>,
+    ) -> Self {
+        DataValidation::new(
+            DataValidationType::ListFormula {
+                ignore_blank: true,
+                formula: format!("={}", absolute_range(sheet_name, range)),
+            },
+            input_message,
+            error_alert,
+        )
+    }
+
     #[must_use]
     pub fn new(
         validation_type: DataValidationType,
@@ -163,6 +227,13 @@ impl DataValidation {
         &self,
         c_string_helper: &mut CStringHelper,
     ) -> Result<CDataValidation, XlsxError> {
+        if let DataValidationType::List { values, .. } = &self.validation_type {
+            let length = values.iter().map(|x| x.chars().count()).sum::<usize>()
+                + values.len().saturating_sub(1);
+            if length > MAX_LIST_LENGTH {
+                return Err(XlsxError::validation_list_too_long(length));
+            }
+        }
         let mut _value_list: Option<Vec<Vec<u8>>> = match &self.validation_type {
             DataValidationType::List { values, .. } => {
                 let mapped_vec = values
@@ -518,4 +589,100 @@ mod test {
         workbook.close()?;
         Ok(())
     }
+
+    #[test]
+    fn test_validation_list_from_range() -> Result<(), XlsxError> {
+        let workbook = Workbook::new("test-worksheet_validation-list_from_range.xlsx")?;
+        let mut worksheet = workbook.add_worksheet(None)?;
+        let mut values = workbook.add_worksheet(Some("Sheet 2"))?;
+        for row in 0..10 {
+            values.write_string(row, 0, &format!("Value {}", row + 1), None)?;
+        }
+
+        let validation = DataValidation::list_from_range(
+            Some("Sheet 2"),
+            &CellRange::new(0, 0, 9, 0),
+            None,
+            None,
+        );
+        assert_eq!(
+            validation.validation_type,
+            DataValidationType::ListFormula {
+                ignore_blank: true,
+                formula: "='Sheet 2'!$A$1:$A$10".to_string(),
+            }
+        );
+        worksheet.data_validation_range(0, 0, 20, 0, &validation)?;
+
+        let local = DataValidation::list_from_range(None, &CellRange::new(0, 2, 0, 2), None, None);
+        assert_eq!(
+            local.validation_type,
+            DataValidationType::ListFormula {
+                ignore_blank: true,
+                formula: "=$C$1".to_string(),
+            }
+        );
+        assert_eq!(
+            absolute_range(Some("O'Brien"), &CellRange::new(1, 0, 4, 1)),
+            "'O''Brien'!$A$2:$B$5"
+        );
+        workbook.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_validation_list_too_long() -> Result<(), XlsxError> {
+        let workbook = Workbook::new("test-worksheet_validation-list_too_long.xlsx")?;
+        let mut worksheet = workbook.add_worksheet(None)?;
+        let list = |values: Vec<String>| {
+            DataValidation::new(
+                DataValidationType::List {
+                    ignore_blank: true,
+                    dropdown: true,
+                    values,
+                },
+                None,
+                None,
+            )
+        };
+
+        // 51 values of 4 characters and 50 commas are exactly 254 characters
+        let values: Vec<String> = (0..51).map(|i| format!("V{:03}", i)).collect();
+        worksheet.data_validation_cell(0, 0, &list(values.clone()))?;
+
+        let mut too_long = values;
+        too_long.push("ab".to_string());
+        let error = worksheet
+            .data_validation_range(1, 0, 10, 0, &list(too_long))
+            .unwrap_err();
+        assert_eq!(
+            error.source,
+            crate::error::XlsxErrorSource::ValidationListTooLong(257)
+        );
+        assert!(error.to_string().contains("list_from_range"));
+        workbook.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_validation_custom_formula() -> Result<(), XlsxError> {
+        let workbook = Workbook::new("test-worksheet_validation-custom_formula.xlsx")?;
+        let validation = DataValidation::new(
+            DataValidationType::CustomFormula {
+                ignore_blank: true,
+                formula: "=ISNUMBER(B2)".to_string(),
+            },
+            None,
+            Some(ErrorAlertOptions {
+                style: DataValidationErrorType::Stop,
+                title: "Not a number".to_string(),
+                message: "Enter a number".to_string(),
+            }),
+        );
+        let mut worksheet = workbook.add_worksheet(None)?;
+        worksheet.write_string(0, 1, "numbers only", None)?;
+        worksheet.data_validation_range(1, 1, 10, 1, &validation)?;
+        workbook.close()?;
+        Ok(())
+    }
 }